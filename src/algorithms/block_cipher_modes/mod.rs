@@ -0,0 +1,1831 @@
+use std::vec;
+
+use zeroize::Zeroize;
+
+use super::{
+    kuznechik::Kuznechik, par_decrypt_blocks, par_encrypt_blocks, par_segments, par_segments_progress, secret::Secret,
+    sum_mod2_slice,
+    BlockCipher, FromMasterKey,
+};
+
+pub mod stream;
+
+/// Шифрование (или расшифрование) одного блока длины n байт выбранным блочным шифром.
+/// Все режимы ниже реализованы для произвольной длины блока n и вызываются
+/// через эту функцию с n = C::BLOCK_SIZE.
+type BlockFn<'a> = &'a dyn Fn(&[u8]) -> Vec<u8>;
+
+/// Число блоков n байт в отрезке ctr_crypt_progress на каждый бит s: отрезок из s * n * CTR_SEGMENT_BLOCKS
+/// байт начинается с нового счетчика и с того же байта гаммы, что и в ctr_crypt.
+pub const CTR_SEGMENT_BLOCKS: usize = 512;
+
+#[allow(dead_code)]
+#[allow(clippy::upper_case_acronyms, reason = "общепринятое название режима, публичное имя сохранено для существующего кода")]
+pub struct CMAC<C: BlockCipher = Kuznechik> {
+    cipher: C,
+    k1: Secret<Vec<u8>>,
+    k2: Secret<Vec<u8>>,
+}
+
+#[allow(dead_code)]
+pub struct CipherModes<C: BlockCipher = Kuznechik> {
+    pub keys: C,
+}
+
+/// Процедура дополнения сообщения до длины, кратной длине блока n (ГОСТ Р 34.13-2018 п. 4.1),
+/// для режимов ECB и CBC. Неполный последний блок, как и во всем крейте, занимает старшие байты
+/// блока, а дополнение - младшие.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Padding {
+    /// Без дополнения: длина сообщения должна быть кратна n
+    None,
+    /// Процедура 1: дополнение нулями. Необратима, поэтому при расшифровании нули не удаляются
+    Procedure1,
+    /// Процедура 2: P || 1 || 0..0, дополняется всегда (при длине, кратной n, - целым блоком)
+    #[default]
+    Procedure2,
+    /// Процедура 3: процедура 2 только для неполного последнего блока. Необратима без длины
+    /// сообщения, поэтому при расшифровании дополнение не удаляется
+    Procedure3,
+}
+
+/// Параметры режимов CTR, OFB, CBC и CFB (ГОСТ Р 34.13-2018), проверенные при создании для блока
+/// длины n байт: s - число бит гаммы (1 <= s <= 8n), z - число блоков в регистре R длины m = n*z
+/// (z >= 1), IV - инициализирующий вектор длины n/2 (CTR) или m байт (OFB, CBC, CFB).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModeParams {
+    s: usize,
+    z: usize,
+    iv: Vec<u8>,
+}
+
+#[allow(dead_code)]
+impl<C: BlockCipher> CMAC<C> {
+    /// Вычисление вспомогательных ключей K1, K2 для CMAC на ключе шифра cipher.
+    /// Константа B определена в ГОСТ Р 34.13-2018 только для блоков 64 и 128 бит.
+    pub fn new(cipher: C) -> Result<Self, String> {
+        if C::BLOCK_SIZE != 8 && C::BLOCK_SIZE != 16 {
+            return Err(format!("CMAC не определен для блока длины {} бит", C::BLOCK_SIZE * 8));
+        }
+
+        let (k1, k2) = cmac_subkeys(C::BLOCK_SIZE, &|block| encrypt_block(&cipher, block));
+
+        Ok(Self {
+            k1: Secret::new(k1),
+            k2: Secret::new(k2),
+            cipher,
+        })
+    }
+
+    /// Процедура вычисления значения имитовставки (Message Authentication Code algorithm),
+    /// где s - число бит имитовставки, а message является сообщением, для которого рассчитывается имитовставка.
+    pub fn cmac(&self, message: &[u8], s: usize) -> Result<Vec<u8>, String> {
+        check_s(s, C::BLOCK_SIZE)?;
+
+        let encrypt = |block: &[u8]| encrypt_block(&self.cipher, block);
+
+        Ok(cmac_compute(C::BLOCK_SIZE, &encrypt, self.k1.expose(), self.k2.expose(), message, s))
+    }
+
+    /// Проверка имитовставки tag длины s = 8 * tag.len() бит для сообщения message.
+    /// Имитовставки сравниваются без раннего выхода, время не зависит от числа совпавших байтов.
+    pub fn verify(&self, message: &[u8], tag: &[u8]) -> Result<bool, String> {
+        let mut expected = self.cmac(message, tag.len() * 8)?;
+        let diff = expected.iter().zip(tag).fold(0u8, |acc, (a, b)| acc | (a ^ b));
+
+        expected.zeroize();
+
+        Ok(diff == 0)
+    }
+}
+
+#[allow(dead_code)]
+impl<C: FromMasterKey> CMAC<C> {
+    /// Имитовставка OMAC-ACPKM (Р 1323565.1.017-2018) длины s бит со сменой ключа каждые
+    /// section байт сообщения. Ключи секций K^i и вспомогательные ключи K^i_1 вырабатываются
+    /// из ключа шифра процедурой ACPKM-Master со сменой ключа каждые master_section байт.
+    /// section и master_section должны быть кратны длине блока n.
+    pub fn omac_acpkm(&self, message: &[u8], section: usize, master_section: usize, s: usize) -> Result<Vec<u8>, String> {
+        let n = C::BLOCK_SIZE;
+
+        check_section(section, n)?;
+        check_section(master_section, n)?;
+        check_s(s, n)?;
+
+        Ok(omac_acpkm(&self.cipher, message, section, master_section, s))
+    }
+}
+
+#[allow(dead_code)]
+impl<C: BlockCipher> CipherModes<C> {
+    /// Создание структуры CipherModes с блочным шифром cipher (вместе с его ключом),
+    /// также обеспечивает вызов методов шифрования/расшифрования блочных шифров
+    pub fn new(cipher: C) -> Self {
+        Self { keys: cipher }
+    }
+
+    // Взятие s старших бит из str_a
+    pub fn msb(str_a: &[u8], s: usize) -> Vec<u8> {
+        msb(str_a, s)
+    }
+
+    /// Режим простой замены (Electronic Codebook). До шифрования сообщение дополняется
+    /// выбранной процедурой padding (ГОСТ Р 34.13-2018 п. 4.1). Без дополнения длина
+    /// сообщения должна быть кратна длине блока.
+    pub fn ecb_encrypt(&self, message: &[u8], padding: Padding) -> Result<Vec<Vec<u8>>, String> {
+        Ok(ecb_encrypt(C::BLOCK_SIZE, &|block| encrypt_block(&self.keys, block), message, padding)?
+            .chunks(C::BLOCK_SIZE)
+            .map(|block| block.to_vec())
+            .collect())
+    }
+
+    /// Режим простой замены (Electronic Codebook). Расшифровывает шифротекст и удаляет
+    /// дополнение процедуры padding. Если дополнение некорректно, возвращается ошибка.
+    pub fn ecb_decrypt(&self, message: &[u8], padding: Padding) -> Result<Vec<u8>, String> {
+        ecb_decrypt(C::BLOCK_SIZE, &|block| decrypt_block(&self.keys, block), message, padding)
+    }
+
+    /// Режим гаммирования (Counter) с входным сообщением message, представленным срезом байтов,
+    /// параметром s в диапазоне [1; n], представляющем число бит шифрования, и IV - инициализирующим вектором
+    /// длины n/2, который для каждого нового сообщения должен формироваться новый. С помощью данного метода
+    /// можно прозводить как шифрование сообщений, так и расшифрование.
+    pub fn ctr_crypt(&self, message: &[u8], s: usize, iv: &[u8]) -> Result<Vec<u8>, String> {
+        let params = ModeParams::ctr(C::BLOCK_SIZE, s, iv)?;
+
+        Ok(ctr_crypt(C::BLOCK_SIZE, &|block| encrypt_block(&self.keys, block), message, &params))
+    }
+
+    /// Режим гаммирования с обратной связью по выходу (Output Feedback) с входным сообщением
+    /// message, представленным срезом байтов, параметром s, представляющем число бит шифрования,
+    /// параметром m = n*z, где z - целое >= 1, а также IV - инициализирующим вектором длины m,
+    /// который для каждого нового сообщения должен формироваться новый. С помощью данного метода
+    /// можно прозводить как шифрование сообщений, так и расшифрование.
+    pub fn ofb_crypt(&self, message: &[u8], s: usize, z: usize, iv: &[u8]) -> Result<Vec<u8>, String> {
+        let params = ModeParams::ofb(C::BLOCK_SIZE, s, z, iv)?;
+
+        Ok(ofb_crypt(C::BLOCK_SIZE, &|block| encrypt_block(&self.keys, block), message, &params))
+    }
+
+    /// Режим простой замены с зацеплением (Cipher Block Chaining) с входным сообщением
+    /// message, представленным срезом байтов, параметром m = n*z, где z - целое >= 1,
+    /// а также IV - инициализирующим вектором длины m, который для каждого нового сообщения должен формироваться новый.
+    /// Данная функция производит шифрование, до которого message дополняется процедурой padding.
+    pub fn cbc_encrypt(&self, message: &[u8], z: usize, iv: &[u8], padding: Padding) -> Result<Vec<u8>, String> {
+        let params = ModeParams::cbc(C::BLOCK_SIZE, z, iv)?;
+
+        cbc_encrypt(C::BLOCK_SIZE, &|block| encrypt_block(&self.keys, block), message, &params, padding)
+    }
+
+    /// Режим простой замены с зацеплением (Cipher Block Chaining) с входным сообщением
+    /// message, представленным срезом байтов, параметром m = n*z, где z - целое >= 1,
+    /// а также IV - инициализирующим вектором длины m, который для каждого нового сообщения должен формироваться новый.
+    /// Данная функция производит расшифрование, после которого удаляется дополнение процедуры padding.
+    /// Если дополнение некорректно, возвращается ошибка.
+    pub fn cbc_decrypt(&self, message: &[u8], z: usize, iv: &[u8], padding: Padding) -> Result<Vec<u8>, String> {
+        let params = ModeParams::cbc(C::BLOCK_SIZE, z, iv)?;
+
+        cbc_decrypt(C::BLOCK_SIZE, &|block| decrypt_block(&self.keys, block), message, &params, padding)
+    }
+
+    /// Режим гаммирования с обратной связью по шифртексту (Cipher Feedback) с входным сообщением
+    /// message, представленным срезом байтов, параметром s, представляющем число бит шифрования,
+    /// параметром m = n*z, где z - целое >= 1, а также IV - инициализирующим вектором длины m,
+    /// который для каждого нового сообщения должен формироваться новый. Данный метод используется
+    /// для шифрования исходного сообщения.
+    pub fn cfb_encrypt(&self, message: &[u8], s: usize, z: usize, iv: &[u8]) -> Result<Vec<u8>, String> {
+        let params = ModeParams::cfb(C::BLOCK_SIZE, s, z, iv)?;
+
+        Ok(cfb_crypt(C::BLOCK_SIZE, &|block| encrypt_block(&self.keys, block), message, &params, false))
+    }
+
+    /// Режим гаммирования с обратной связью по шифртексту (Cipher Feedback) с входным сообщением
+    /// message, представленным срезом байтов, параметром s, представляющем число бит шифрования,
+    /// параметром m = n*z, где z - целое >= 1, а также IV - инициализирующим вектором длины m,
+    /// который для каждого нового сообщения должен формироваться новый. Данный метод используется для
+    /// расшифрования шифротекса.
+    pub fn cfb_decrypt(&self, message: &[u8], s: usize, z: usize, iv: &[u8]) -> Result<Vec<u8>, String> {
+        let params = ModeParams::cfb(C::BLOCK_SIZE, s, z, iv)?;
+
+        Ok(cfb_crypt(C::BLOCK_SIZE, &|block| encrypt_block(&self.keys, block), message, &params, true))
+    }
+}
+
+#[allow(dead_code)]
+impl<C: FromMasterKey> CipherModes<C> {
+    /// Режим гаммирования со сменой ключа CTR-ACPKM (Р 1323565.1.017-2018): сообщение делится
+    /// на секции по section байт (кратно длине блока n), для каждой следующей секции ключ
+    /// заменяется на ACPKM(K), счетчик продолжается. IV длины n/2. Гамма берется целыми блоками (s = n),
+    /// поэтому при section >= длины сообщения результат совпадает с ctr_crypt(message, 8n, iv).
+    pub fn ctr_acpkm_crypt(&self, message: &[u8], section: usize, iv: &[u8]) -> Result<Vec<u8>, String> {
+        check_section(section, C::BLOCK_SIZE)?;
+        let params = ModeParams::ctr(C::BLOCK_SIZE, C::BLOCK_SIZE * 8, iv)?;
+
+        Ok(ctr_acpkm(&self.keys, message, section, params.iv()))
+    }
+}
+
+#[allow(dead_code)]
+impl<C: BlockCipher + Sync> CipherModes<C> {
+    /// Режим простой замены (Electronic Codebook) с шифрованием блоков в нескольких потоках.
+    /// Результат совпадает с ecb_encrypt.
+    pub fn ecb_encrypt_par(&self, message: &[u8], padding: Padding) -> Result<Vec<Vec<u8>>, String> {
+        let n = C::BLOCK_SIZE;
+
+        let mut blocks = pad(message, n, padding)?;
+        par_encrypt_blocks(&self.keys, &mut blocks);
+
+        Ok(blocks.chunks(n).map(|block| block.to_vec()).collect())
+    }
+
+    /// Режим простой замены (Electronic Codebook) с расшифрованием блоков в нескольких потоках.
+    /// Результат совпадает с ecb_decrypt.
+    pub fn ecb_decrypt_par(&self, message: &[u8], padding: Padding) -> Result<Vec<u8>, String> {
+        let n = C::BLOCK_SIZE;
+
+        if !message.len().is_multiple_of(n) {
+            return Err(format!("Длина должна быть кратна {} битам", n * 8));
+        }
+
+        let mut decrypted_message = message.to_vec();
+        par_decrypt_blocks(&self.keys, &mut decrypted_message);
+        unpad(&mut decrypted_message, n, padding)?;
+
+        Ok(decrypted_message)
+    }
+
+    /// Режим гаммирования (Counter) с вычислением гаммы в нескольких потоках.
+    /// Параметры и результат совпадают с ctr_crypt.
+    pub fn ctr_crypt_par(&self, message: &[u8], s: usize, iv: &[u8]) -> Result<Vec<u8>, String> {
+        let n = C::BLOCK_SIZE;
+        let params = ModeParams::ctr(n, s, iv)?;
+
+        let mut res = message.to_vec();
+
+        // Каждый поток сам вычисляет счетчики для своего отрезка по его смещению
+        par_segments(&mut res, n, &|offset, segment| {
+            ctr_apply_gamma(n, &|block| encrypt_block(&self.keys, block), segment, offset, params.s, &params.iv)
+        });
+
+        Ok(res)
+    }
+
+    /// Режим гаммирования (Counter) для больших данных: message делится на отрезки по
+    /// s * n * CTR_SEGMENT_BLOCKS байт, счетчик каждого отрезка вычисляется из IV по его номеру,
+    /// и отрезки обрабатываются в нескольких потоках. После каждого отрезка вызывается
+    /// progress(обработано байт, всего байт). Параметры и результат совпадают с ctr_crypt.
+    pub fn ctr_crypt_progress(
+        &self,
+        message: &[u8],
+        s: usize,
+        iv: &[u8],
+        progress: &(dyn Fn(usize, usize) + Sync),
+    ) -> Result<Vec<u8>, String> {
+        let n = C::BLOCK_SIZE;
+        let params = ModeParams::ctr(n, s, iv)?;
+
+        let segment_len = s * n * CTR_SEGMENT_BLOCKS;
+        let mut res = message.to_vec();
+
+        par_segments_progress(
+            &mut res,
+            segment_len,
+            &|idx, segment| {
+                // CTR = IV||0..0 + номер первого счетчика отрезка
+                let mut ctr: Vec<u8> = vec![0; n];
+                ctr[n / 2..].copy_from_slice(&params.iv);
+                add_ctr_by(&mut ctr, idx * segment_len * 8 / s);
+
+                let mut gamma = ctr_crypt_from(n, &|block| encrypt_block(&self.keys, block), segment, s, ctr);
+                segment.copy_from_slice(&gamma);
+                gamma.zeroize();
+            },
+            progress,
+        );
+
+        Ok(res)
+    }
+}
+
+#[allow(dead_code)]
+impl ModeParams {
+    /// Параметры режима гаммирования: IV длины n/2 байт
+    pub fn ctr(n: usize, s: usize, iv: &[u8]) -> Result<Self, String> {
+        check_s(s, n)?;
+        check_iv(iv, n / 2)?;
+
+        Ok(Self { s, z: 1, iv: iv.to_vec() })
+    }
+
+    /// Параметры режима гаммирования с обратной связью по выходу: IV длины n*z байт
+    pub fn ofb(n: usize, s: usize, z: usize, iv: &[u8]) -> Result<Self, String> {
+        check_s(s, n)?;
+        check_register(iv, n, z)?;
+
+        Ok(Self { s, z, iv: iv.to_vec() })
+    }
+
+    /// Параметры режима простой замены с зацеплением: IV длины n*z байт, s = n
+    pub fn cbc(n: usize, z: usize, iv: &[u8]) -> Result<Self, String> {
+        check_register(iv, n, z)?;
+
+        Ok(Self { s: n * 8, z, iv: iv.to_vec() })
+    }
+
+    /// Параметры режима гаммирования с обратной связью по шифротексту: IV длины n*z байт
+    pub fn cfb(n: usize, s: usize, z: usize, iv: &[u8]) -> Result<Self, String> {
+        check_s(s, n)?;
+        check_register(iv, n, z)?;
+
+        Ok(Self { s, z, iv: iv.to_vec() })
+    }
+
+    /// Число бит гаммы (блока текста), обрабатываемых за один шаг
+    pub fn s(&self) -> usize {
+        self.s
+    }
+
+    /// Число блоков в регистре R
+    pub fn z(&self) -> usize {
+        self.z
+    }
+
+    /// Инициализирующий вектор
+    pub fn iv(&self) -> &[u8] {
+        &self.iv
+    }
+}
+
+// 1 <= s <= 8n
+fn check_s(s: usize, n: usize) -> Result<(), String> {
+    if s < 1 || s > n * 8 {
+        return Err(format!("Параметр s должен лежать в [1; {}]", n * 8));
+    }
+
+    Ok(())
+}
+
+fn check_iv(iv: &[u8], len: usize) -> Result<(), String> {
+    if iv.len() != len {
+        return Err(format!("Длина IV должна быть {} байт", len));
+    }
+
+    Ok(())
+}
+
+// Регистр R длины m = n*z байт, z >= 1
+fn check_register(iv: &[u8], n: usize, z: usize) -> Result<(), String> {
+    if z < 1 {
+        return Err("Параметр z должен быть >= 1".to_string());
+    }
+
+    match n.checked_mul(z) {
+        Some(m) => check_iv(iv, m),
+        None => Err(format!("Слишком большой параметр z = {}", z)),
+    }
+}
+
+/// Преобразование ACPKM (Р 1323565.1.017-2018): новый ключ K' = MSB_256(e_K(D_1)||...||e_K(D_J)),
+/// где D = 80||81||...||9F - константа длины 256 бит, разбитая на блоки шифра.
+pub fn acpkm<C: FromMasterKey>(cipher: &C) -> C {
+    // D в little-endian: байты 9F..80, блоки D_J..D_1
+    let mut key: [u8; 32] = std::array::from_fn(|idx| 0x9F - idx as u8);
+    cipher.encrypt_blocks(&mut key);
+
+    let res = C::from_master_key(&key);
+    key.zeroize();
+
+    res
+}
+
+/// Выработка двух ключей по 256 бит из ключа шифра cipher KDF в режиме счетчика (NIST SP 800-108)
+/// с CMAC в качестве PRF: K_1 || K_2 = CMAC_K(1 || label || 0 || 512) || CMAC_K(2 || ...) || ...
+/// Разные label дают независимые пары ключей, сам ключ cipher для данных не используется.
+pub fn kdf_cmac<C: FromMasterKey>(cipher: C, label: &[u8]) -> Result<(C, C), String> {
+    let n = C::BLOCK_SIZE;
+    let prf = CMAC::new(cipher)?;
+
+    let mut keys = vec![];
+    for idx in 1..=(64 / n) as u8 {
+        let data = [&[idx][..], label, &[0x00], &512u16.to_le_bytes()].concat();
+        keys.extend(prf.cmac(&data, n * 8)?);
+    }
+
+    let mut k1 = [0u8; 32];
+    let mut k2 = [0u8; 32];
+    k1.copy_from_slice(&keys[..32]);
+    k2.copy_from_slice(&keys[32..]);
+
+    let res = (C::from_master_key(&k1), C::from_master_key(&k2));
+
+    keys.zeroize();
+    k1.zeroize();
+    k2.zeroize();
+
+    Ok(res)
+}
+
+// Длина секции ACPKM - ненулевое целое число блоков
+fn check_section(section: usize, n: usize) -> Result<(), String> {
+    if section == 0 || !section.is_multiple_of(n) {
+        return Err(format!("Длина секции должна быть ненулевой и кратной {} байтам", n));
+    }
+
+    Ok(())
+}
+
+// Режим CTR-ACPKM: секция i шифруется в режиме гаммирования ключом ACPKM^(i-1)(K)
+// с продолжением счетчика (номер счетчика вычисляется по смещению секции)
+fn ctr_acpkm<C: FromMasterKey>(cipher: &C, message: &[u8], section: usize, iv: &[u8]) -> Vec<u8> {
+    let n = C::BLOCK_SIZE;
+
+    let mut res = message.to_vec();
+    let mut meshed: Option<C> = None;
+
+    for (idx, segment) in res.chunks_mut(section).enumerate() {
+        if idx > 0 {
+            meshed = Some(acpkm(meshed.as_ref().unwrap_or(cipher)));
+        }
+
+        let key = meshed.as_ref().unwrap_or(cipher);
+        ctr_apply_gamma(n, &|block| encrypt_block(key, block), segment, idx * section, n * 8, iv);
+    }
+
+    res
+}
+
+// OMAC-ACPKM: K^1||K^1_1||...||K^l||K^l_1 = ACPKM-Master(T*, K, (k + n)l), где ACPKM-Master -
+// гамма CTR-ACPKM с секцией T* на IV = 1^(n/2); блоки секции i сцепляются на ключе K^i,
+// последний блок складывается с K^l_1 (полный) или с K^l_1 << 1 (xor B) (дополненный)
+fn omac_acpkm<C: FromMasterKey>(cipher: &C, message: &[u8], section: usize, master_section: usize, s: usize) -> Vec<u8> {
+    let n = C::BLOCK_SIZE;
+    let keys_len = 32 + n;
+
+    let sections = message.len().div_ceil(section).max(1);
+    let mut master = ctr_acpkm(cipher, &vec![0u8; keys_len * sections], master_section, &vec![0xFF; n / 2]);
+
+    // Пустое сообщение - один неполный блок
+    let blocks: Vec<&[u8]> = if message.is_empty() { vec![&[]] } else { message.chunks(n).collect() };
+
+    let mut c = vec![0u8; n];
+    let mut mac = vec![0u8; n];
+    let mut current: Option<(usize, C)> = None;
+
+    for (idx, block) in blocks.iter().enumerate() {
+        let j = idx * n / section;
+
+        // Ключ секции: гамма в big-endian K^j||K^j_1, в little-endian блоки ключа идут в обратном порядке
+        if current.as_ref().map(|(cur, _)| *cur) != Some(j) {
+            let mut key = [0u8; 32];
+            let master_key = &master[j * keys_len..j * keys_len + 32];
+            for (dst, src) in key.chunks_mut(n).zip(master_key.chunks(n).rev()) {
+                dst.copy_from_slice(src);
+            }
+
+            current = Some((j, C::from_master_key(&key)));
+            key.zeroize();
+        }
+
+        let key = &current.as_ref().expect("Ключ секции вычислен выше").1;
+
+        if idx + 1 < blocks.len() {
+            // C_i = e_K^j(M_i + C_(i-1))
+            c = encrypt_block(key, &sum_mod2_slice(block, &c).unwrap());
+            continue;
+        }
+
+        let k1 = &master[j * keys_len + 32..(j + 1) * keys_len];
+        let (last, mut k_star) = if block.len() == n {
+            (block.to_vec(), k1.to_vec())
+        } else {
+            (padding_proc2(block, n), cmac_double(k1, n))
+        };
+
+        let last = sum_mod2_slice(&sum_mod2_slice(&last, &c).unwrap(), &k_star).unwrap();
+        mac = encrypt_block(key, &last);
+
+        k_star.zeroize();
+    }
+
+    master.zeroize();
+    c.zeroize();
+
+    // s старших бит
+    let res = msb(&mac, s)[(n * 8 - s) / 8..].to_vec();
+    mac.zeroize();
+
+    res
+}
+
+// Зашифрование копии блока шифром cipher
+fn encrypt_block<C: BlockCipher>(cipher: &C, block: &[u8]) -> Vec<u8> {
+    let mut res = block.to_vec();
+    cipher.encrypt_block(&mut res);
+
+    res
+}
+
+// Расшифрование копии блока шифром cipher
+fn decrypt_block<C: BlockCipher>(cipher: &C, block: &[u8]) -> Vec<u8> {
+    let mut res = block.to_vec();
+    cipher.decrypt_block(&mut res);
+
+    res
+}
+
+/// Производит дополнение блока длины n байт по алгоритму ГОСТ Р 34.13-2018 paragraph 4.1.3
+fn padding_proc2(message: &[u8], n: usize) -> Vec<u8> {
+    let mut padded_message: Vec<u8> = vec![0; n];
+
+    padded_message[n - message.len()..].copy_from_slice(message); // P || 0..0
+    padded_message[n - message.len() - 1] = 0b1000_0000; // P || 10..0
+
+    padded_message
+}
+
+// 5.2.1 (3): Ctr + 1 в кольце Z_2^n
+fn add_ctr(ctr: &mut [u8]) {
+    for byte in ctr.iter_mut() {
+        let (res, overflow) = byte.overflowing_add(1);
+        *byte = res;
+
+        if !overflow {
+            break;
+        }
+    }
+}
+
+// Ctr + k в кольце Z_2^n
+fn add_ctr_by(ctr: &mut [u8], k: usize) {
+    let mut carry = k as u128;
+
+    for byte in ctr.iter_mut() {
+        if carry == 0 {
+            break;
+        }
+
+        let sum = *byte as u128 + (carry & 0xFF);
+        *byte = sum as u8;
+        carry = (carry >> 8) + (sum >> 8);
+    }
+}
+
+// Взятие s старших бит из str_a
+fn msb(str_a: &[u8], s: usize) -> Vec<u8> {
+    let mut res = str_a.to_vec();
+    let len_res = res.len() * 8;
+
+    // Сколько бит и байт занулить
+    let byte_count = (len_res - s) / 8;
+    let bit_count = (len_res - s) % 8;
+
+    if bit_count != 0 {
+        // Маска для оставления только старших bit_idx бит
+        let mask: u8 = 0xFF << bit_count;
+        res[byte_count] &= mask;
+    }
+
+    // Обнуляем все байты после нужного
+    res[..byte_count].fill(0);
+
+    res
+}
+
+// Дополнение сообщения процедурой padding до длины, кратной n байт
+fn pad(message: &[u8], n: usize, padding: Padding) -> Result<Vec<u8>, String> {
+    let full_len = message.len() - message.len() % n;
+    let tail = &message[full_len..];
+
+    let mut res = message[..full_len].to_vec();
+
+    match padding {
+        Padding::None if !tail.is_empty() => {
+            return Err(format!("Без дополнения длина должна быть кратна {} битам", n * 8));
+        }
+        // P || 0..0
+        Padding::Procedure1 if !tail.is_empty() => {
+            let mut block = vec![0u8; n];
+            block[n - tail.len()..].copy_from_slice(tail);
+            res.extend_from_slice(&block);
+        }
+        Padding::Procedure2 => res.extend_from_slice(&padding_proc2(tail, n)),
+        Padding::Procedure3 if !tail.is_empty() => res.extend_from_slice(&padding_proc2(tail, n)),
+        _ => {}
+    }
+
+    Ok(res)
+}
+
+// Удаление дополнения процедуры 2 из последнего блока: младшие байты блока должны быть
+// нулями, за которыми следует 1000 0000. Иначе - ошибка, данные не отбрасываются.
+fn unpad(message: &mut Vec<u8>, n: usize, padding: Padding) -> Result<(), String> {
+    if padding != Padding::Procedure2 {
+        return Ok(());
+    }
+
+    if message.len() < n || !message.len().is_multiple_of(n) {
+        return Err("Некорректное дополнение: нет последнего блока".to_string());
+    }
+
+    let last = message.len() - n;
+    let one = match message[last..].iter().position(|&byte| byte != 0) {
+        Some(idx) if message[last + idx] == 0b1000_0000 => idx,
+        _ => return Err("Некорректное дополнение: последний блок не дополнен процедурой 2".to_string()),
+    };
+
+    message.drain(last..=last + one);
+
+    Ok(())
+}
+
+// Сдвиг младших байтов регистра R длины m на n байт и запись block в освободившиеся байты:
+// R = LSB_[m-n](R) || block
+fn shift_register(r: &mut [u8], n: usize, block: &[u8]) {
+    let m = r.len();
+
+    // Младшие биты регистра R
+    let lsb_r = r[..(m - n)].to_vec();
+
+    r[n..].copy_from_slice(&lsb_r);
+    r[..n].copy_from_slice(block);
+}
+
+// Вспомогательные ключи K1, K2 CMAC (ГОСТ Р 34.13-2018 п. 5.6.1)
+fn cmac_subkeys(n: usize, encrypt: BlockFn) -> (Vec<u8>, Vec<u8>) {
+    let zeroes = vec![0u8; n];
+
+    let mut r = encrypt(&zeroes);
+    let k1 = cmac_double(&r, n);
+    let k2 = cmac_double(&k1, n);
+
+    // Затирание промежуточных значений
+    r.zeroize();
+
+    (k1, k2)
+}
+
+// R << 1, если MSB_1(R) = 0, иначе (R << 1) xor B
+fn cmac_double(r: &[u8], n: usize) -> Vec<u8> {
+    // В_128 = 0^120 | 10000111, B_64 = 0^59 | 11011
+    let mut b = vec![0u8; n];
+    b[0] = if n == 16 { 0b1000_0111 } else { 0b0001_1011 };
+
+    let mut res = vec![0u8; n];
+
+    // R << 1
+    for idx in 0..n {
+        if idx == n - 1 {
+            res[0] = r[0] << 1;
+        } else {
+            res[n - 1 - idx] = (r[n - 1 - idx] << 1) | (r[n - 1 - idx - 1] >> 7);
+        }
+    }
+
+    // MSB_1(R) != 0
+    if (r[n - 1] & 0b1000_0000) != 0 {
+        res = sum_mod2_slice(&res, &b).unwrap();
+    }
+
+    res
+}
+
+// Вычисление имитовставки длины s бит (ГОСТ Р 34.13-2018 п. 5.6)
+fn cmac_compute(n: usize, encrypt: BlockFn, k1: &[u8], k2: &[u8], message: &[u8], s: usize) -> Vec<u8> {
+    let mut mac: Vec<u8> = vec![0; n];
+    let mut c: Vec<u8> = vec![0; n];
+    let mut chunk_u8: Vec<u8>;
+
+    // Пустое сообщение - один неполный блок 10..0, дополненный по процедуре 2 и сложенный с K2
+    if message.is_empty() {
+        mac = encrypt(&sum_mod2_slice(&padding_proc2(&[], n), k2).unwrap());
+    }
+
+    // Взятие по n байт
+    for (cur_chunk, chunk) in message.chunks(n).enumerate() {
+        chunk_u8 = vec![0; n];
+        chunk_u8[0..chunk.len()].copy_from_slice(chunk);
+
+        // Если последние биты message
+        if chunk.len() != n || ((cur_chunk + 1) * n >= message.len()) {
+            // Дополнение
+            if chunk.len() < n {
+                chunk_u8 = padding_proc2(chunk, n);
+            }
+
+            // P_q + C_(q-1)
+            chunk_u8 = sum_mod2_slice(&chunk_u8, &c).unwrap();
+
+            // Длина последнего блока n байт
+            if chunk.len() == n {
+                chunk_u8 = sum_mod2_slice(&chunk_u8, k1).unwrap();
+            } else {
+                chunk_u8 = sum_mod2_slice(&chunk_u8, k2).unwrap();
+            }
+
+            mac = encrypt(&chunk_u8);
+        } else {
+            // C_i = e_k(P_i + C_(i-1))
+            c = encrypt(&sum_mod2_slice(&chunk_u8, &c).unwrap());
+        }
+    }
+
+    // Удаление из массива зануленной (обрезанной) части
+    let mut vec_mac = msb(&mac[..], s);
+    for _ in 0..((n * 8 - s) / 8) {
+        vec_mac.remove(0);
+    }
+
+    vec_mac
+}
+
+// Режим простой замены, шифрование. Результат - последовательность блоков длины n байт
+fn ecb_encrypt(n: usize, encrypt: BlockFn, message: &[u8], padding: Padding) -> Result<Vec<u8>, String> {
+    let mut encrypted_message: Vec<u8> = vec![];
+
+    // Шифрование блоками по n байт
+    for chunk in pad(message, n, padding)?.chunks(n) {
+        encrypted_message.extend_from_slice(&encrypt(chunk));
+    }
+
+    Ok(encrypted_message)
+}
+
+// Режим простой замены, расшифрование
+fn ecb_decrypt(n: usize, decrypt: BlockFn, message: &[u8], padding: Padding) -> Result<Vec<u8>, String> {
+    let mut encrypted_message: Vec<u8> = vec![];
+
+    // Расшифрование блоками по n байт
+    for chunk in message.chunks(n) {
+        if chunk.len() != n {
+            return Err(format!("Длина должна быть кратна {} битам", n * 8));
+        }
+
+        encrypted_message.extend_from_slice(&decrypt(chunk));
+    }
+
+    unpad(&mut encrypted_message, n, padding)?;
+
+    Ok(encrypted_message)
+}
+
+// Режим гаммирования, IV длины n/2 байт
+fn ctr_crypt(n: usize, encrypt: BlockFn, message: &[u8], params: &ModeParams) -> Vec<u8> {
+    // CTR1 = IV||0..0
+    let mut ctr: Vec<u8> = vec![0; n];
+    ctr[n / 2..].copy_from_slice(&params.iv);
+
+    ctr_crypt_from(n, encrypt, message, params.s, ctr)
+}
+
+// Режим гаммирования с первым счетчиком ctr, s - число бит, которые будут шифроваться
+fn ctr_crypt_from(n: usize, encrypt: BlockFn, message: &[u8], s: usize, mut ctr: Vec<u8>) -> Vec<u8> {
+    let mut res: Vec<u8> = vec![];
+
+    let mut c = 0; // C_i
+    let mut cur_idx = 0; // Текущий обрабатываемый бит
+    let mut cur_byte = 0; // Текущий обрабатываемый байт
+
+    // Шифрование сообщения блоками длины s
+    loop {
+        // Проверка, что все биты обработаны
+        if (cur_idx + cur_byte * 8) >= message.len() * 8 {
+            break;
+        }
+
+        // Сколько осталось отработать бит из s на данный момент
+        let mut rem_bits = s;
+
+        let ek_ctr = encrypt(&ctr);
+
+        // Зануление младших бит у ctr
+        let gamma_u8 = msb(&ek_ctr, s);
+
+        // Обработка rem_bit = s
+        let mut byte_m: u8; // Байт message
+        let mut byte_ctr: u8; // Байт Ctr
+
+        // Пока остались биты на обработку
+        while rem_bits != 0 {
+            if cur_byte >= message.len() {
+                break;
+            }
+
+            // Если message[cur_byte] = 0101 1100 и cur_idx = 3
+            byte_m = message[cur_byte] >> cur_idx; // Зануление младших обработанных битов даст byte = 0000 1011
+            byte_m <<= cur_idx; // Возврат к исходному положению не зануленных бит byte = 0101 1000
+
+            // Часть ctr
+            byte_ctr = gamma_u8[cur_byte % n] >> cur_idx;
+            byte_ctr <<= cur_idx;
+
+            // Если операция выполняется на нескольких байтах
+            if (cur_idx + rem_bits) > 8 {
+                rem_bits -= 8 - cur_idx;
+
+                // Переход к следующему байту
+                cur_idx = 0;
+                cur_byte += 1;
+
+                c |= byte_m ^ byte_ctr; // Суммирование по модулю 2
+                res.push(c); // Помещение результата в массив
+                c = 0; // Обнуление результата нового байта
+            }
+            // Если операция выполняется на одном байте
+            else {
+                // Если message[cur_byte] = 0001 1100 и cur_idx = 3 и s = 2
+                byte_m >>= cur_idx; // Зануление младших обработанных битов byte = 0000 0011
+                byte_m <<= cur_idx; // Возврат к исходному положению byte = 0001 1000
+                byte_m <<= 8 - (cur_idx + rem_bits); // Зануление старших бит, которые обрабатывать не надо, даст byte = 1100 0000
+                byte_m >>= 8 - (cur_idx + rem_bits); // Возврат к исходному положению byte = 0001 100
+
+                // Часть ctr
+                byte_ctr >>= cur_idx;
+                byte_ctr <<= cur_idx;
+                byte_ctr <<= 8 - (cur_idx + rem_bits);
+                byte_ctr >>= 8 - (cur_idx + rem_bits);
+
+                c |= byte_m ^ byte_ctr; // Суммирование по модулю 2 (Если s < 8, то добавляем части предыдущего результата)
+
+                // Сдвиг индекса обрабатываемого бита
+                if (cur_idx + rem_bits) == 8 {
+                    // Если полностью обработан текущий байт
+                    cur_byte += 1;
+                    cur_idx = 0;
+
+                    res.push(c);
+                    c = 0;
+                } else {
+                    cur_idx += rem_bits;
+                };
+
+                rem_bits -= rem_bits;
+            }
+        }
+
+        add_ctr(&mut ctr);
+    }
+
+    res
+}
+
+// Наложение гаммы режима гаммирования на отрезок segment, начинающийся с байта offset сообщения.
+// В ctr_crypt i-й бит сообщения (по порядку обработки) складывается с битом i % 8 байта
+// (i / 8) % n гаммы MSB_s(e_k(CTR_(i / s))), поэтому отрезки можно обрабатывать независимо.
+fn ctr_apply_gamma(n: usize, encrypt: BlockFn, segment: &mut [u8], offset: usize, s: usize, iv: &[u8]) {
+    let mut ctr_idx: Option<usize> = None; // Номер счетчика текущей гаммы
+    let mut gamma_u8: Vec<u8> = vec![];
+
+    for (idx, byte) in segment.iter_mut().enumerate() {
+        let cur_byte = offset + idx;
+        let mut gamma_byte = 0;
+
+        for bit in 0..8 {
+            let j = (cur_byte * 8 + bit) / s;
+
+            // Вычисление гаммы MSB_s(e_k(CTR_j)), CTR_j = IV||0..0 + j
+            if ctr_idx != Some(j) {
+                let mut ctr: Vec<u8> = vec![0; n];
+                ctr[n / 2..].copy_from_slice(iv);
+                add_ctr_by(&mut ctr, j);
+
+                gamma_u8.zeroize();
+                gamma_u8 = msb(&encrypt(&ctr), s);
+                ctr_idx = Some(j);
+            }
+
+            gamma_byte |= gamma_u8[cur_byte % n] & (1 << bit);
+        }
+
+        *byte ^= gamma_byte;
+    }
+
+    gamma_u8.zeroize();
+}
+
+// Режим гаммирования с обратной связью по выходу, m = n*z байт
+fn ofb_crypt(n: usize, encrypt: BlockFn, message: &[u8], params: &ModeParams) -> Vec<u8> {
+    let s = params.s; // s - число бит, которые будут шифроваться
+
+    let m = n * params.z; // Длина m в байтах
+    let mut r = params.iv.clone(); // Регистр длины m байт
+
+    let mut res: Vec<u8> = vec![];
+
+    let mut c = 0; // Байт результата
+    let mut cur_idx = 0; // Текущий обрабатываемый бит
+    let mut cur_byte = 0; // Текущий обрабатываемый байт
+
+    // Шифрование сообщения блоками длины s со сдвигом регистра R
+    loop {
+        // Проверка, что все биты обработаны
+        if (cur_idx + cur_byte * 8) >= message.len() * 8 {
+            break;
+        }
+
+        // Сколько осталось отработать бит из s на данный момент
+        let mut rem_bits = s;
+
+        // Передача старшей части регистра R
+        let ek_r = encrypt(&r[(m - n)..]);
+
+        // Зануление младших бит у ek_r (T_s)
+        let gamma_u8 = msb(&ek_r, s);
+
+        // Обработка rem_bit = s
+        let mut byte_m: u8; // Байт message
+        let mut byte_ctr: u8; // Байт Ctr
+
+        // Пока остались биты на обработку
+        while rem_bits != 0 {
+            if cur_byte >= message.len() {
+                break;
+            }
+
+            // Если message[cur_byte] = 0101 1100 и cur_idx = 3
+            byte_m = message[cur_byte] >> cur_idx; // Зануление младших обработанных битов даст byte = 0000 1011
+            byte_m <<= cur_idx; // Возврат к исходному положению не зануленных бит byte = 0101 1000
+
+            // Часть ctr
+            byte_ctr = gamma_u8[cur_byte % n] >> cur_idx;
+            byte_ctr <<= cur_idx;
+
+            // Если операция выполняется на нескольких байтах
+            if (cur_idx + rem_bits) > 8 {
+                rem_bits -= 8 - cur_idx;
+
+                // Переход к следующему байту
+                cur_idx = 0;
+                cur_byte += 1;
+
+                c |= byte_m ^ byte_ctr; // Суммирование по модулю 2
+                res.push(c); // Помещение результата в массив
+                c = 0; // Обнуление результата нового байта
+            }
+            // Если операция выполняется на одном байте
+            else {
+                // Если message[cur_byte] = 0001 1100 и cur_idx = 3 и s = 2
+                byte_m >>= cur_idx; // Зануление младших обработанных битов byte = 0000 0011
+                byte_m <<= cur_idx; // Возврат к исходному положению byte = 0001 1000
+                byte_m <<= 8 - (cur_idx + rem_bits); // Зануление старших бит, которые обрабатывать не надо, даст byte = 1100 0000
+                byte_m >>= 8 - (cur_idx + rem_bits); // Возврат к исходному положению byte = 0001 100
+
+                // Часть ctr
+                byte_ctr >>= cur_idx;
+                byte_ctr <<= cur_idx;
+                byte_ctr <<= 8 - (cur_idx + rem_bits);
+                byte_ctr >>= 8 - (cur_idx + rem_bits);
+
+                c |= byte_m ^ byte_ctr; // Суммирование по модулю 2 (Если s < 8, то добавляем части предыдущего результата)
+
+                // Сдвиг индекса обрабатываемого бита
+                if (cur_idx + rem_bits) == 8 {
+                    // Если полностью обработан текущий байт
+                    cur_byte += 1;
+                    cur_idx = 0;
+
+                    res.push(c);
+                    c = 0;
+                } else {
+                    cur_idx += rem_bits;
+                };
+
+                rem_bits -= rem_bits;
+            }
+        }
+
+        // R = LSB_[m-n](R) || ek_r
+        shift_register(&mut r, n, &ek_r);
+    }
+
+    res
+}
+
+// Режим простой замены с зацеплением, шифрование, m = n*z байт
+fn cbc_encrypt(n: usize, encrypt: BlockFn, message: &[u8], params: &ModeParams, padding: Padding) -> Result<Vec<u8>, String> {
+    let m = n * params.z; // Длина m в байтах
+    let mut r = params.iv.clone(); // Регистр длины m байт
+
+    let mut res: Vec<u8> = vec![];
+
+    for chunk in pad(message, n, padding)?.chunks(n) {
+        let c = encrypt(&sum_mod2_slice(chunk, &r[(m - n)..]).unwrap());
+
+        res.extend_from_slice(&c);
+
+        // R = LSB_[m-n](R) || C
+        shift_register(&mut r, n, &c);
+    }
+
+    Ok(res)
+}
+
+// Режим простой замены с зацеплением, расшифрование, m = n*z байт
+fn cbc_decrypt(n: usize, decrypt: BlockFn, message: &[u8], params: &ModeParams, padding: Padding) -> Result<Vec<u8>, String> {
+    if !message.len().is_multiple_of(n) {
+        return Err(format!("Длина должна быть кратна {} битам", n * 8));
+    }
+
+    let m = n * params.z; // Длина m в байтах
+    let mut r = params.iv.clone(); // Регистр длины m байт
+
+    let mut res: Vec<u8> = vec![];
+
+    for chunk in message.chunks(n) {
+        // P = D_k(C) sum_mod_2 MSB_n(R)
+        let p = sum_mod2_slice(&decrypt(chunk), &r[(m - n)..]).unwrap();
+
+        // R = LSB_[m-n](R) || C
+        shift_register(&mut r, n, chunk);
+
+        res.extend_from_slice(&p);
+    }
+
+    unpad(&mut res, n, padding)?;
+
+    Ok(res)
+}
+
+// Сдвиг регистра R режима CFB на s бит в сторону старших разрядов
+fn shift_register_bits(r: &mut [u8], s: usize) {
+    // Сколько байтов и битов сдвиг s
+    let len = r.len();
+    let byte_shifted = (s / 8).min(len);
+    let bit_shifted = s % 8;
+
+    // Перенос байтов, освободившиеся младшие байты зануляются
+    r.copy_within(..len - byte_shifted, byte_shifted);
+    r[..byte_shifted].fill(0);
+
+    // Сдвиг на оставшиеся биты с переносом старших бит в следующий байт
+    if bit_shifted != 0 {
+        let mut carry = 0u8;
+        for byte in r.iter_mut() {
+            let next = *byte >> (8 - bit_shifted);
+            *byte = (*byte << bit_shifted) | carry;
+            carry = next;
+        }
+    }
+}
+
+// Режим гаммирования с обратной связью по шифртексту, m = n*z байт.
+// При decrypt = true в регистр заносится входной шифротекст, иначе - полученный.
+fn cfb_crypt(n: usize, encrypt: BlockFn, message: &[u8], params: &ModeParams, decrypt: bool) -> Vec<u8> {
+    let s = params.s; // s - число бит, которые будут шифроваться
+
+    let m = n * params.z; // Длина m в байтах
+    let mut r = params.iv.clone(); // Регистр длины m байт
+
+    let mut res: Vec<u8> = vec![];
+
+    let mut c = 0; // Байт результата
+    let mut p = 0; // Байт входного текста
+    let mut cur_idx = 0; // Текущий обрабатываемый бит
+    let mut cur_byte = 0; // Текущий обрабатываемый байт
+
+    // Шифрование сообщения блоками длины s со сдвигом регистра R
+    loop {
+        // Проверка, что все биты обработаны
+        if (cur_idx + cur_byte * 8) >= message.len() * 8 {
+            break;
+        }
+
+        let mut cipher_text_c: Vec<u8> = vec![]; // Используется для занесения в регистр R значения C
+
+        // Сколько осталось отработать бит из s на данный момент
+        let mut rem_bits = s;
+
+        // Передача старшей части регистра R
+        let ek_r = encrypt(&r[(m - n)..]);
+
+        // Зануление младших бит у ek_r (T_s)
+        let gamma_u8 = msb(&ek_r, s);
+
+        // Обработка rem_bit = s
+        let mut byte_m: u8; // Байт message
+        let mut byte_ctr: u8; // Байт Ctr
+
+        // Пока остались биты на обработку
+        while rem_bits != 0 {
+            if cur_byte >= message.len() {
+                break;
+            }
+
+            // Если message[cur_byte] = 0101 1100 и cur_idx = 3
+            byte_m = message[cur_byte] >> cur_idx; // Зануление младших обработанных битов даст byte = 0000 1011
+            byte_m <<= cur_idx; // Возврат к исходному положению не зануленных бит byte = 0101 1000
+
+            // Часть ctr
+            byte_ctr = gamma_u8[cur_byte % n] >> cur_idx;
+            byte_ctr <<= cur_idx;
+
+            // Если операция выполняется на нескольких байтах
+            if (cur_idx + rem_bits) > 8 {
+                rem_bits -= 8 - cur_idx;
+
+                // Переход к следующему байту
+                cur_idx = 0;
+                cur_byte += 1;
+
+                c |= byte_m ^ byte_ctr; // Суммирование по модулю 2
+                p |= byte_m;
+                cipher_text_c.push(if decrypt { p } else { c }); // Для сдвига регистра R и занесения в сдвинутые биты C
+                res.push(c); // Помещение результата в массив
+                c = 0; // Обнуление результата нового байта
+                p = 0;
+            }
+            // Если операция выполняется на одном байте
+            else {
+                // Если message[cur_byte] = 0001 1100 и cur_idx = 3 и s = 2
+                byte_m <<= 8 - (cur_idx + rem_bits); // Зануление старших бит, которые обрабатывать не надо, даст byte = 1100 0000
+                byte_m >>= 8 - (cur_idx + rem_bits); // Возврат к исходному положению byte = 0001 100
+
+                // Часть ctr
+                byte_ctr <<= 8 - (cur_idx + rem_bits);
+                byte_ctr >>= 8 - (cur_idx + rem_bits);
+
+                p |= byte_m;
+                c |= byte_m ^ byte_ctr; // Суммирование по модулю 2 (Если s < 8, то добавляем части предыдущего результата)
+
+                // Сдвиг индекса обрабатываемого бита
+                if (cur_idx + rem_bits) == 8 {
+                    // Если полностью обработан текущий байт
+                    cur_byte += 1;
+                    cur_idx = 0;
+
+                    cipher_text_c.push(if decrypt { p } else { c }); // Для сдвига регистра R и занесения в сдвинутые биты C
+                    res.push(c);
+                    c = 0;
+                    p = 0;
+                } else {
+                    cur_idx += rem_bits;
+                };
+
+                rem_bits -= rem_bits;
+            }
+        }
+
+        // Сдвиг регистра R на s бит
+        shift_register_bits(&mut r, s);
+
+        // Занесение C в регистр R
+        for (r_byte, c_byte) in r.iter_mut().zip(&cipher_text_c) {
+            *r_byte |= c_byte;
+        }
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::algorithms::{
+        hex_to_bytes,
+        kuznechik::KuznechikKey,
+        magma::{Magma, MagmaKey},
+        random_vec,
+        self_test::{le_blocks, KUZNECHIK_VECTORS, MAGMA_VECTORS},
+        sum_mod2,
+    };
+    // Note this useful idiom: importing names from outer (for mod tests) scope.
+    use super::*;
+
+    #[test]
+    fn test_cmac() {
+        let mut r = hex_to_bytes("94bec15e269cf1e506f02b994c0a8ea0");
+        r.reverse();
+
+        // КОД ИЗ ФУНКЦИИ CMAC::generate_keys()
+        // В_128= 0^128 | 10000111
+        let mut b: [u8; 16] = [0; 16];
+        b[0] = 135;
+
+        let mut k1: [u8; 16] = [0; 16];
+        let mut k2: [u8; 16] = [0; 16];
+
+        // R << 1
+        for idx in 0..16 {
+            if idx == 15 {
+                k1[0] = r[0] << 1;
+            } else {
+                k1[15 - idx] = (r[15 - idx] << 1) | (r[15 - idx - 1] >> 7);
+            }
+        }
+
+        // MSB_1(R) != 0
+        if (r[15] & 0b1000_0000) != 0 {
+            k1 = sum_mod2(&k1, &b);
+        }
+
+        // K1 << 1
+        for idx in 0..16 {
+            if idx == 15 {
+                k2[0] = k1[0] << 1;
+            } else {
+                k2[15 - idx] = (k1[15 - idx] << 1) | (k1[15 - idx - 1] >> 7);
+            }
+        }
+
+        // MSB_1(K1) != 0
+        if (k1[15] & 0b1000_0000) != 0 {
+            k2 = sum_mod2(&k2, &b);
+        }
+
+        let k1_gost = hex_to_bytes("297d82bc4d39e3ca0de0573298151dc7");
+        let k2_gost = hex_to_bytes("52fb05789a73c7941bc0ae65302a3b8e");
+
+        k1.reverse();
+        k2.reverse();
+
+        assert_eq!(k1.to_vec(), k1_gost);
+        assert_eq!(k2.to_vec(), k2_gost);
+
+        // Сообщение и начальный ключ K из ГОСТ Р 34.13-2018 А.1
+        let p = le_blocks(&KUZNECHIK_VECTORS.p);
+        k1.reverse();
+        k2.reverse();
+
+        let cmac = CMAC::new(kuznechik_gost()).unwrap();
+
+        // Вспомогательные ключи, вычисленные по ключу K, совпадают с ключами из ГОСТ Р
+        assert_eq!(cmac.k1.expose().to_vec(), k1);
+        assert_eq!(cmac.k2.expose().to_vec(), k2);
+
+        let res = cmac.cmac(&p, KUZNECHIK_VECTORS.mac_s).unwrap();
+
+        assert_eq!(res, le_blocks(&[KUZNECHIK_VECTORS.mac]));
+
+        // Имитовставка длины s бит занимает ceil(s / 8) байт
+        assert_eq!(cmac.cmac(&p, 128).unwrap().len(), 16);
+        assert_eq!(cmac.cmac(&p, 12).unwrap().len(), 2);
+
+        // Проверка имитовставки, в том числе укороченной (старшие байты полной)
+        let tag = cmac.cmac(&p, 128).unwrap();
+        assert!(cmac.verify(&p, &tag).unwrap());
+        assert!(cmac.verify(&p, &tag[8..]).unwrap());
+        assert!(!cmac.verify(&p[1..], &tag).unwrap());
+
+        let mut bad_tag = tag.clone();
+        bad_tag[15] ^= 0x80;
+        assert!(!cmac.verify(&p, &bad_tag).unwrap());
+        assert!(cmac.verify(&p, &[]).is_err());
+        assert!(cmac.verify(&p, &[tag.clone(), vec![0]].concat()).is_err());
+
+        // Другой ключ - другая имитовставка
+        assert!(!CMAC::new(Kuznechik::new()).unwrap().verify(&p, &tag).unwrap());
+    }
+
+    #[test]
+    fn test_cmac_empty_message() {
+        let cmac = CMAC::new(kuznechik_gost()).unwrap();
+        let tag = cmac.cmac(&[], 128).unwrap();
+
+        // MAC(пустое сообщение) = e_K(10..0 + K2)
+        let mut block = vec![0u8; 16];
+        block[15] = 0x80;
+        let expected = encrypt_block(&kuznechik_gost(), &sum_mod2_slice(&block, cmac.k2.expose()).unwrap());
+
+        assert_eq!(tag, expected);
+        assert_ne!(tag, vec![0u8; 16]);
+        assert!(!cmac.verify(&[], &[0u8; 16]).unwrap());
+        assert!(cmac.verify(&[], &tag).unwrap());
+
+        // Имитовставка пустого сообщения зависит от ключа
+        let other = CMAC::new(Kuznechik::new()).unwrap().cmac(&[], 128).unwrap();
+        assert_ne!(tag, other);
+
+        let magma_tag = CMAC::new(magma_gost()).unwrap().cmac(&[], 64).unwrap();
+        assert_ne!(magma_tag, vec![0u8; 8]);
+    }
+
+    #[test]
+    fn test_shift_register_bits() {
+        let r: Vec<u8> = (1..=16).collect();
+        let value = u128::from_le_bytes(r.clone().try_into().unwrap());
+
+        for s in [1, 3, 8, 12, 64, 100, 120, 127] {
+            let mut shifted = r.clone();
+            shift_register_bits(&mut shifted, s);
+            assert_eq!(shifted, (value << s).to_le_bytes());
+        }
+
+        // Регистр из нескольких блоков
+        let mut r: Vec<u8> = (1..=32).collect();
+        shift_register_bits(&mut r, 8);
+        assert_eq!(r, (0..32).collect::<Vec<u8>>());
+    }
+
+    #[test]
+    fn test_ecb_encrypt_decrypt() {
+        let p = le_blocks(&KUZNECHIK_VECTORS.p);
+
+        let kuz_ecb = CipherModes::new(kuznechik_gost());
+
+        let result = kuz_ecb.ecb_encrypt(&p, Padding::None).unwrap();
+
+        // Правильный результат шифротекста
+        assert_eq!(result.concat(), le_blocks(&KUZNECHIK_VECTORS.ecb));
+
+        let decrypt_result = kuz_ecb.ecb_decrypt(&result.concat(), Padding::None).unwrap();
+
+        assert_eq!(decrypt_result, p);
+    }
+
+    #[test]
+    fn test_ecb_encrypt_decrypt_with_padding() {
+        let message =
+            "Hello world!!! That's message generate automatically. Or not. POOP!".as_bytes();
+
+        let kuz_ecb = CipherModes::new(Kuznechik::new());
+
+        let encrypted_result = kuz_ecb.ecb_encrypt(message, Padding::Procedure2).unwrap();
+        let decrypted_result = kuz_ecb
+            .ecb_decrypt(&encrypted_result.into_iter().flatten().collect::<Vec<u8>>()[..], Padding::Procedure2)
+            .unwrap();
+
+        //println!("Decrypted result = {}", String::from_utf8(decrypted_result.clone()).unwrap());
+
+        assert_eq!(decrypted_result, message);
+    }
+
+    #[test]
+    fn test_padding_procedures() {
+        let kuz = CipherModes::new(Kuznechik::new());
+        let iv = random_vec(32);
+
+        // Число блоков после дополнения сообщений длины 0, 5, 16, 21 байт
+        let expected = [
+            (Padding::None, [Some(0), None, Some(1), None]),
+            (Padding::Procedure1, [Some(0), Some(1), Some(1), Some(2)]),
+            (Padding::Procedure2, [Some(1), Some(1), Some(2), Some(2)]),
+            (Padding::Procedure3, [Some(0), Some(1), Some(1), Some(2)]),
+        ];
+
+        for (padding, blocks) in expected {
+            for (len, count) in [0, 5, 16, 21].into_iter().zip(blocks) {
+                let mut p = random_vec(len);
+                p.iter_mut().for_each(|byte| *byte |= 1);
+
+                let c = kuz.ecb_encrypt(&p, padding).ok().map(|c| c.len());
+                assert_eq!(c, count, "{:?}, {} байт", padding, len);
+
+                if count.is_none() {
+                    assert!(kuz.cbc_encrypt(&p, 2, &iv, padding).is_err());
+                    continue;
+                }
+
+                // Процедура 2 обратима, для остальных расшифрованный текст дополнен до целого числа блоков
+                let c = kuz.cbc_encrypt(&p, 2, &iv, padding).unwrap();
+                let res = kuz.cbc_decrypt(&c, 2, &iv, padding).unwrap();
+                if padding == Padding::Procedure2 || len % 16 == 0 {
+                    assert_eq!(res, p);
+                } else {
+                    // Неполный блок занимает старшие байты последнего блока
+                    let full = len / 16 * 16;
+                    assert_eq!(res[..full], p[..full]);
+                    assert_eq!(res[res.len() - len % 16..], p[full..]);
+                    assert_eq!(res.len(), c.len());
+                }
+            }
+        }
+
+        // Дополнение процедуры 1 - нули в младших байтах последнего блока
+        let c = kuz.ecb_encrypt(&[0xAA; 3], Padding::Procedure1).unwrap().concat();
+        assert_eq!(kuz.ecb_decrypt(&c, Padding::None).unwrap(), [vec![0; 13], vec![0xAA; 3]].concat());
+
+        // Некорректное дополнение не отбрасывается, а приводит к ошибке
+        for last in [vec![0u8; 16], [vec![0; 15], vec![1]].concat(), [vec![0; 3], vec![0x81], vec![5; 12]].concat()] {
+            let c = kuz.ecb_encrypt(&last, Padding::None).unwrap().concat();
+            assert!(kuz.ecb_decrypt(&c, Padding::Procedure2).is_err());
+            assert!(kuz.ecb_decrypt_par(&c, Padding::Procedure2).is_err());
+        }
+        assert!(kuz.ecb_decrypt(&[], Padding::Procedure2).is_err());
+        assert!(kuz.cbc_decrypt(&random_vec(20), 2, &iv, Padding::None).is_err());
+
+        // Данные, оканчивающиеся байтами 0x80 и 0x00, сохраняются
+        let p = [vec![1; 20], vec![0x80, 0, 0x80]].concat();
+        let c = kuz.ecb_encrypt(&p, Padding::Procedure2).unwrap().concat();
+        assert_eq!(kuz.ecb_decrypt(&c, Padding::Procedure2).unwrap(), p);
+    }
+
+    #[test]
+    fn test_ctr_encrypt_decrypt_gost() {
+        // IV - initalizing vector
+        let iv = le_blocks(&[KUZNECHIK_VECTORS.ctr_iv]);
+        let p = le_blocks(&KUZNECHIK_VECTORS.p);
+
+        // Формирование итерационных ключей
+        let kuz_ecb = CipherModes::new(kuznechik_gost());
+
+        // Шифрование
+        let res = kuz_ecb.ctr_crypt(&p, 128, &iv).unwrap();
+
+        // Правильные значения шифротекста
+        assert_eq!(res, le_blocks(&KUZNECHIK_VECTORS.ctr));
+
+        // Расшифрование
+        let decrypt_res = kuz_ecb.ctr_crypt(&res, 128, &iv).unwrap();
+
+        assert_eq!(decrypt_res, p);
+    }
+
+    #[test]
+    fn test_ctr_encrypt_decrypt_any_s() {
+        // IV - initalizing vector
+        let iv = le_blocks(&[KUZNECHIK_VECTORS.ctr_iv]);
+
+        // Формирование итерационных ключей
+        let kuz_ecb = CipherModes::new(kuznechik_gost());
+
+        let mut p = hex_to_bytes("1337abcdefabababaabababababbababbbbbbababa");
+        p.reverse();
+
+        // Проверка всего диапазона s от 1 до 128
+        for s in 1..129 {
+            // Шифрование
+            let res_encrypt = kuz_ecb.ctr_crypt(&p, s, &iv).unwrap();
+            let res_decrypt = kuz_ecb.ctr_crypt(&res_encrypt, s, &iv).unwrap();
+
+            assert_eq!(p, res_decrypt);
+        }
+    }
+
+    #[test]
+    fn test_ofb_encrypt_decrypt() {
+        let p = le_blocks(&KUZNECHIK_VECTORS.p);
+
+        // Формирование итерационных ключей
+        let kuz_ecb = CipherModes::new(kuznechik_gost());
+
+        let iv = le_blocks(&[KUZNECHIK_VECTORS.ofb_cfb_iv]);
+
+        // Шифрование
+        let res = kuz_ecb.ofb_crypt(&p, 128, 2, &iv).unwrap();
+
+        // Правильные значения шифротекста
+        assert_eq!(res, le_blocks(&KUZNECHIK_VECTORS.ofb));
+
+        let decrypt_res = kuz_ecb.ofb_crypt(&res, 128, 2, &iv).unwrap();
+
+        assert_eq!(decrypt_res, p);
+
+        // Тестирование для разных параметров s, z, iv
+        for s in 1..128 {
+            for z in 1..4 {
+                let iv = random_vec(z * 16); // Генерация случайного IV
+
+                // Шифрование
+                let res = kuz_ecb.ofb_crypt(&p, s, z, &iv).unwrap();
+
+                // Расшифрование
+                let decrypt_res = kuz_ecb.ofb_crypt(&res, s, z, &iv).unwrap();
+
+                assert_eq!(decrypt_res, p);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cbc_encrypt_decrypt() {
+        let p = le_blocks(&KUZNECHIK_VECTORS.p);
+
+        // Формирование итерационных ключей
+        let kuz_ecb = CipherModes::new(kuznechik_gost());
+
+        let iv = le_blocks(&[KUZNECHIK_VECTORS.cbc_iv]);
+        let z = KUZNECHIK_VECTORS.cbc_z;
+        let res = kuz_ecb.cbc_encrypt(&p, z, &iv, Padding::None).unwrap();
+
+        // Правильные значения шифротекста
+        assert_eq!(res, le_blocks(&KUZNECHIK_VECTORS.cbc));
+
+        let res_decrypt = kuz_ecb.cbc_decrypt(&res, z, &iv, Padding::None).unwrap();
+
+        assert_eq!(res_decrypt, p);
+
+        // Проверка для случайных IV и z
+        for z in 1..4 {
+            let iv = random_vec(16 * z);
+            let result_random_iv = kuz_ecb.cbc_encrypt(&p, z, &iv, Padding::Procedure2).unwrap();
+            let result_random_iv_decrypt = kuz_ecb.cbc_decrypt(&result_random_iv, z, &iv, Padding::Procedure2).unwrap();
+
+            assert_eq!(p, result_random_iv_decrypt);
+        }
+    }
+
+    #[test]
+    fn test_cfb_encrypt_decrypt() {
+        let p = le_blocks(&KUZNECHIK_VECTORS.p);
+
+        // Формирование итерационных ключей
+        let kuz_ecb = CipherModes::new(kuznechik_gost());
+
+        let iv = le_blocks(&[KUZNECHIK_VECTORS.ofb_cfb_iv]);
+
+        let res_encrypt = kuz_ecb.cfb_encrypt(&p, 128, 2, &iv).unwrap();
+
+        // Правильные значения шифротекста
+        assert_eq!(res_encrypt, le_blocks(&KUZNECHIK_VECTORS.cfb));
+
+        let res_decrypt = kuz_ecb.cfb_decrypt(&res_encrypt, 128, 2, &iv).unwrap();
+
+        assert_eq!(res_decrypt, p);
+
+        // Тестирование для разных параметров s, z, iv
+        for s in 1..128 {
+            for z in 1..4 {
+                let iv = random_vec(z * 16); // Генерация случайного IV
+
+                // Шифрование
+                let res = kuz_ecb.cfb_encrypt(&p, s, z, &iv).unwrap();
+
+                // Расшифрование
+                let decrypt_res = kuz_ecb.cfb_decrypt(&res, s, z, &iv).unwrap();
+
+                assert_eq!(decrypt_res, p);
+            }
+        }
+    }
+
+    // Шифры на ключах из ГОСТ Р 34.13-2018
+    fn kuznechik_gost() -> Kuznechik {
+        Kuznechik::with_key(KuznechikKey::from_hex(KUZNECHIK_VECTORS.key).unwrap())
+    }
+
+    fn magma_gost() -> Magma {
+        Magma::with_key(MagmaKey::from_hex(MAGMA_VECTORS.key).unwrap())
+    }
+
+    #[test]
+    fn test_magma_ecb_ctr_gost() {
+        let v = &MAGMA_VECTORS;
+        let (magma, p) = (CipherModes::new(magma_gost()), le_blocks(&v.p));
+
+        // ECB
+        let res: Vec<u8> = magma.ecb_encrypt(&p, Padding::None).unwrap().concat();
+
+        assert_eq!(res, le_blocks(&v.ecb));
+        assert_eq!(magma.ecb_decrypt(&res, Padding::None).unwrap(), p);
+
+        // CTR с IV длины 32 бита
+        let iv = le_blocks(&[v.ctr_iv]);
+        let res = magma.ctr_crypt(&p, 64, &iv).unwrap();
+
+        assert_eq!(res, le_blocks(&v.ctr));
+        assert_eq!(magma.ctr_crypt(&res, 64, &iv).unwrap(), p);
+    }
+
+    #[test]
+    fn test_magma_ofb_cbc_cfb_gost() {
+        let v = &MAGMA_VECTORS;
+        let (magma, p) = (CipherModes::new(magma_gost()), le_blocks(&v.p));
+
+        // OFB, m = 128 (z = 2)
+        let iv = le_blocks(&[v.ofb_cfb_iv]);
+        let res = magma.ofb_crypt(&p, 64, 2, &iv).unwrap();
+
+        assert_eq!(res, le_blocks(&v.ofb));
+        assert_eq!(magma.ofb_crypt(&res, 64, 2, &iv).unwrap(), p);
+
+        // CFB, m = 128 (z = 2)
+        let res = magma.cfb_encrypt(&p, 64, 2, &iv).unwrap();
+
+        assert_eq!(res, le_blocks(&v.cfb));
+        assert_eq!(magma.cfb_decrypt(&res, 64, 2, &iv).unwrap(), p);
+
+        // CBC, m = 192 (z = 3)
+        let iv = le_blocks(&[v.cbc_iv]);
+        let res = magma.cbc_encrypt(&p, v.cbc_z, &iv, Padding::None).unwrap();
+
+        assert_eq!(res, le_blocks(&v.cbc));
+        assert_eq!(magma.cbc_decrypt(&res, v.cbc_z, &iv, Padding::None).unwrap(), p);
+    }
+
+    #[test]
+    fn test_magma_cmac_gost() {
+        let p = le_blocks(&MAGMA_VECTORS.p);
+        let cmac = CMAC::new(magma_gost()).unwrap();
+
+        // Вспомогательные ключи K1, K2 из ГОСТ Р 34.13-2018 А.2.6
+        let mut k1 = hex_to_bytes("5f459b3342521424");
+        k1.reverse();
+        let mut k2 = hex_to_bytes("be8b366684a42848");
+        k2.reverse();
+
+        assert_eq!(cmac.k1.expose().to_vec(), k1);
+        assert_eq!(cmac.k2.expose().to_vec(), k2);
+
+        let res = cmac.cmac(&p, MAGMA_VECTORS.mac_s).unwrap();
+
+        assert_eq!(res, le_blocks(&[MAGMA_VECTORS.mac]));
+    }
+
+    #[test]
+    fn test_mode_params_validation() {
+        let kuz = CipherModes::new(Kuznechik::new());
+        let magma = CipherModes::new(Magma::new());
+        let p = random_vec(40);
+        let iv = random_vec(32);
+
+        // s вне [1; 8n]
+        for s in [0, 129] {
+            assert!(kuz.ctr_crypt(&p, s, &iv[..8]).is_err());
+            assert!(kuz.ctr_crypt_par(&p, s, &iv[..8]).is_err());
+            assert!(kuz.ofb_crypt(&p, s, 2, &iv).is_err());
+            assert!(kuz.cfb_encrypt(&p, s, 2, &iv).is_err());
+            assert!(kuz.cfb_decrypt(&p, s, 2, &iv).is_err());
+            assert!(CMAC::new(Kuznechik::new()).unwrap().cmac(&p, s).is_err());
+        }
+        assert!(magma.ctr_crypt(&p, 65, &iv[..4]).is_err());
+
+        // z = 0 и IV, не совпадающий по длине с регистром n*z
+        assert!(kuz.ofb_crypt(&p, 128, 0, &[]).is_err());
+        assert!(kuz.cbc_encrypt(&p, 0, &[], Padding::Procedure2).is_err());
+        assert!(kuz.cfb_encrypt(&p, 128, usize::MAX, &iv).is_err());
+        assert!(kuz.ofb_crypt(&p, 128, 1, &iv).is_err());
+        assert!(kuz.cbc_decrypt(&p[..32], 3, &iv, Padding::None).is_err());
+        assert!(kuz.cfb_decrypt(&p, 128, 2, &iv[1..]).is_err());
+        assert!(kuz.ctr_crypt(&p, 128, &iv[..4]).is_err());
+        assert!(kuz.ctr_acpkm_crypt(&p, 32, &iv).is_err());
+        assert!(magma.cbc_encrypt(&p, 3, &iv, Padding::Procedure2).is_err());
+
+        let params = ModeParams::cfb(16, 8, 2, &iv).unwrap();
+        assert_eq!((params.s(), params.z(), params.iv()), (8, 2, &iv[..]));
+        assert_eq!(ModeParams::cbc(8, 4, &iv).unwrap().s(), 64);
+        assert!(ModeParams::ctr(8, 64, &iv[..8]).is_err());
+    }
+
+    #[test]
+    fn test_parallel_ecb_ctr_match_sequential() {
+        let kuz = CipherModes::new(Kuznechik::new());
+        let magma = CipherModes::new(Magma::new());
+
+        for len in [1, 15, 16, 17, 100, 1000] {
+            let p = random_vec(len);
+
+            for padding in [Padding::Procedure1, Padding::Procedure2, Padding::Procedure3] {
+                assert_eq!(kuz.ecb_encrypt_par(&p, padding), kuz.ecb_encrypt(&p, padding));
+                let c = kuz.ecb_encrypt(&p, padding).unwrap().concat();
+                assert_eq!(kuz.ecb_decrypt_par(&c, padding).unwrap(), kuz.ecb_decrypt(&c, padding).unwrap());
+                assert_eq!(magma.ecb_encrypt_par(&p, padding), magma.ecb_encrypt(&p, padding));
+            }
+
+            let iv = random_vec(8);
+            assert_eq!(kuz.ctr_crypt_par(&p, 128, &iv).unwrap(), kuz.ctr_crypt(&p, 128, &iv).unwrap());
+            assert_eq!(magma.ctr_crypt_par(&p, 64, &iv[..4]).unwrap(), magma.ctr_crypt(&p, 64, &iv[..4]).unwrap());
+        }
+
+        assert!(kuz.ecb_decrypt_par(&random_vec(17), Padding::None).is_err());
+
+        // Все s, в том числе не кратные 8 (при малых s счетчиков больше 256)
+        let p = random_vec(67);
+        let mut iv = random_vec(4);
+        for s in 1..65 {
+            assert_eq!(magma.ctr_crypt_par(&p, s, &iv).unwrap(), magma.ctr_crypt(&p, s, &iv).unwrap(), "s = {}", s);
+        }
+
+        // Отрезки с ненулевым смещением (как при нескольких потоках)
+        let mut res = p.clone();
+        for (idx, segment) in res.chunks_mut(16).enumerate() {
+            ctr_apply_gamma(8, &|block| encrypt_block(&magma.keys, block), segment, idx * 16, 13, &iv);
+        }
+        assert_eq!(res, magma.ctr_crypt(&p, 13, &iv).unwrap());
+
+        let mut ctr = vec![0xFF, 0xFF, 0xFF, 0x00];
+        add_ctr_by(&mut ctr, 2);
+        assert_eq!(ctr, vec![0x01, 0x00, 0x00, 0x01]);
+
+        iv.fill(0xFF);
+        assert_eq!(magma.ctr_crypt_par(&p, 7, &iv).unwrap(), magma.ctr_crypt(&p, 7, &iv).unwrap());
+    }
+
+    #[test]
+    fn test_ctr_crypt_progress() {
+        use std::sync::Mutex;
+
+        let kuz = CipherModes::new(Kuznechik::new());
+        let magma = CipherModes::new(Magma::new());
+
+        // Несколько отрезков (при s = 1 у Магмы отрезок 4096 байт), в том числе неполный последний
+        let p = random_vec(10_000);
+        let iv = random_vec(8);
+
+        for s in [1, 7, 13, 64] {
+            let calls = Mutex::new(vec![]);
+            let res = magma.ctr_crypt_progress(&p, s, &iv[..4], &|done, total| calls.lock().unwrap().push((done, total))).unwrap();
+            assert_eq!(res, magma.ctr_crypt(&p, s, &iv[..4]).unwrap(), "s = {}", s);
+
+            let mut calls = calls.into_inner().unwrap();
+            calls.sort();
+            assert_eq!(calls.len(), p.len().div_ceil(s * 8 * CTR_SEGMENT_BLOCKS));
+            assert_eq!(calls.last(), Some(&(p.len(), p.len())));
+        }
+
+        let res = kuz.ctr_crypt_progress(&p, 24, &iv, &|_, _| {}).unwrap();
+        assert_eq!(res, kuz.ctr_crypt(&p, 24, &iv).unwrap());
+        assert_eq!(kuz.ctr_crypt_progress(&[], 128, &iv, &|_, _| panic!()).unwrap(), vec![]);
+        assert!(kuz.ctr_crypt_progress(&p, 0, &iv, &|_, _| {}).is_err());
+        assert!(kuz.ctr_crypt_progress(&p, 128, &iv[..4], &|_, _| {}).is_err());
+    }
+
+    // Блоки Кузнечика из big-endian записи в little-endian
+    fn kuz_blocks(blocks: &[&str]) -> Vec<u8> {
+        blocks
+            .iter()
+            .flat_map(|block| {
+                let mut block = hex_to_bytes(block);
+                block.reverse();
+                block
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_ctr_acpkm_omac_acpkm() {
+        let key = KuznechikKey::from_hex("8899aabbccddeeff0011223344556677fedcba98765432100123456789abcdef").unwrap();
+        let kuz = CipherModes::new(Kuznechik::with_key(key.clone_secret()));
+
+        // Р 1323565.1.017-2018 (RFC 8645), приложение A: Кузнечик, N = 256 бит
+        let p = kuz_blocks(&[
+            "1122334455667700ffeeddccbbaa9988",
+            "00112233445566778899aabbcceeff0a",
+            "112233445566778899aabbcceeff0a00",
+            "2233445566778899aabbcceeff0a0011",
+            "33445566778899aabbcceeff0a001122",
+            "445566778899aabbcceeff0a00112233",
+            "5566778899aabbcceeff0a0011223344",
+        ]);
+        let c = kuz_blocks(&[
+            "f195d8bec10ed1dbd57b5fa240bda1b8",
+            "85eee733f6a13e5df33ce4b33c45dee4",
+            "4bceeb8f646f4c55001706275e85e800",
+            "587c4df568d094393e4834afd0805046",
+            "cf30f57686aeece11cfc6c316b8a896e",
+            "dffd07ec813636460c4f3b743423163e",
+            "6409a9c282fac8d469d221e7fbd6de5d",
+        ]);
+        let mut iv = hex_to_bytes("1234567890abcef0");
+        iv.reverse();
+
+        assert_eq!(kuz.ctr_acpkm_crypt(&p, 32, &iv).unwrap(), c);
+        assert_eq!(kuz.ctr_acpkm_crypt(&c, 32, &iv).unwrap(), p);
+
+        // Без смены ключа - обычный режим гаммирования
+        assert_eq!(kuz.ctr_acpkm_crypt(&p, p.len(), &iv).unwrap(), kuz.ctr_crypt(&p, 128, &iv).unwrap());
+
+        // OMAC-ACPKM: N = 256 бит, T* = 768 бит, 5 блоков сообщения
+        let cmac = CMAC::new(Kuznechik::with_key(key)).unwrap();
+        let t = kuz_blocks(&["fbb8dcee45bea67c35f58c5700898e5d"]);
+
+        assert_eq!(cmac.omac_acpkm(&p[..80], 32, 96, 128).unwrap(), t);
+        assert_eq!(cmac.omac_acpkm(&p[..80], 32, 96, 64).unwrap(), t[8..].to_vec());
+        assert_ne!(cmac.omac_acpkm(&p[..79], 32, 96, 128).unwrap(), t);
+
+        // Длина секции должна быть кратна длине блока
+        assert!(kuz.ctr_acpkm_crypt(&p, 20, &iv).is_err());
+        assert!(cmac.omac_acpkm(&p, 32, 0, 128).is_err());
+
+        // Магма: расшифрование и неполный последний блок
+        let magma = CipherModes::new(Magma::with_key(MagmaKey::generate()));
+        let p = random_vec(61);
+        let iv = random_vec(4);
+        let c = magma.ctr_acpkm_crypt(&p, 16, &iv).unwrap();
+        assert_eq!(magma.ctr_acpkm_crypt(&c, 16, &iv).unwrap(), p);
+        assert_ne!(c, magma.ctr_crypt(&p, 64, &iv).unwrap());
+    }
+
+    // Шифр, реализованный вне крейта: поворот байтов блока длины 32 бита и XOR с ключом
+    struct ToyCipher {
+        key: u8,
+    }
+
+    impl BlockCipher for ToyCipher {
+        const BLOCK_SIZE: usize = 4;
+
+        fn encrypt_block(&self, block: &mut [u8]) {
+            block.rotate_left(1);
+            block.iter_mut().for_each(|b| *b ^= self.key);
+        }
+
+        fn decrypt_block(&self, block: &mut [u8]) {
+            block.iter_mut().for_each(|b| *b ^= self.key);
+            block.rotate_right(1);
+        }
+    }
+
+    #[test]
+    fn test_custom_block_cipher() {
+        let modes = CipherModes::new(ToyCipher { key: 0x5a });
+        let p = random_vec(4 * 5);
+        let iv = random_vec(4 * 2);
+
+        assert_eq!(modes.ecb_encrypt(&p, Padding::None).unwrap().len(), 5);
+        assert_eq!(modes.ecb_decrypt(&modes.ecb_encrypt(&p, Padding::Procedure2).unwrap().concat(), Padding::Procedure2).unwrap(), p);
+        assert_eq!(modes.ctr_crypt(&modes.ctr_crypt(&p, 32, &iv[..2]).unwrap(), 32, &iv[..2]).unwrap(), p);
+        assert_eq!(modes.ofb_crypt(&modes.ofb_crypt(&p, 16, 2, &iv).unwrap(), 16, 2, &iv).unwrap(), p);
+        let c = modes.cbc_encrypt(&p, 2, &iv, Padding::Procedure2).unwrap();
+        assert_eq!(modes.cbc_decrypt(&c, 2, &iv, Padding::Procedure2).unwrap(), p);
+        assert_eq!(modes.cfb_decrypt(&modes.cfb_encrypt(&p, 32, 2, &iv).unwrap(), 32, 2, &iv).unwrap(), p);
+
+        // Константа B для CMAC определена только для блоков 64 и 128 бит
+        assert!(CMAC::new(ToyCipher { key: 0x5a }).is_err());
+    }
+}
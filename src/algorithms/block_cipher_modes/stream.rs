@@ -0,0 +1,500 @@
+use std::io::{self, Read, Write};
+
+use zeroize::Zeroize;
+
+use super::{
+    ctr_apply_gamma, encrypt_block, msb, pad, shift_register, shift_register_bits, sum_mod2_slice, unpad,
+    BlockCipher, CipherModes, ModeParams, Padding,
+};
+
+// Размер порции, читаемой из источника в CipherReader
+const READ_CHUNK: usize = 64 * 1024;
+
+// Режим работы и его параметры, не меняющиеся в процессе обработки
+enum Mode {
+    Ecb { padding: Padding },
+    Ctr { s: usize, iv: Vec<u8> },
+    Ofb { s: usize },
+    Cbc { padding: Padding },
+    Cfb { s: usize },
+}
+
+/// Потоковое зашифрование или расшифрование в одном из режимов ГОСТ Р 34.13-2018.
+/// Данные подаются частями произвольной длины через update, в конце вызывается finalize.
+/// Между вызовами хранятся регистр R, текущая гамма и неполный блок, поэтому результат
+/// совпадает с одноименными методами CipherModes для всего сообщения, а память не зависит
+/// от длины сообщения. Для ввода-вывода используются обертки writer и reader.
+pub struct StreamCipher<'a, C: BlockCipher> {
+    cipher: &'a C,
+    mode: Mode,
+    decrypt: bool,
+    offset: usize,       // Число обработанных байт входа
+    register: Vec<u8>,   // Регистр R длины m = n*z байт (OFB, CBC, CFB)
+    gamma: Vec<u8>,      // e_k(MSB_n(R)) текущего шага (OFB, CFB)
+    gamma_s: Vec<u8>,    // MSB_s(gamma) - гамма, накладываемая на текст
+    completed: Vec<u8>,  // Байты шифротекста, завершенные на текущем шаге (CFB)
+    pending: Vec<u8>,    // Неполный блок, а при расшифровании ECB и CBC - последний блок
+    finalized: bool,
+}
+
+#[allow(dead_code)]
+impl<C: BlockCipher> CipherModes<C> {
+    /// Потоковое зашифрование в режиме простой замены (см. ecb_encrypt)
+    pub fn ecb_encryptor(&self, padding: Padding) -> StreamCipher<'_, C> {
+        StreamCipher::new(&self.keys, Mode::Ecb { padding }, false, vec![])
+    }
+
+    /// Потоковое расшифрование в режиме простой замены (см. ecb_decrypt)
+    pub fn ecb_decryptor(&self, padding: Padding) -> StreamCipher<'_, C> {
+        StreamCipher::new(&self.keys, Mode::Ecb { padding }, true, vec![])
+    }
+
+    /// Потоковый режим гаммирования (см. ctr_crypt), одинаков для зашифрования и расшифрования
+    pub fn ctr_stream(&self, s: usize, iv: &[u8]) -> Result<StreamCipher<'_, C>, String> {
+        let params = ModeParams::ctr(C::BLOCK_SIZE, s, iv)?;
+
+        Ok(StreamCipher::new(&self.keys, Mode::Ctr { s, iv: params.iv }, false, vec![]))
+    }
+
+    /// Потоковый режим гаммирования с обратной связью по выходу (см. ofb_crypt)
+    pub fn ofb_stream(&self, s: usize, z: usize, iv: &[u8]) -> Result<StreamCipher<'_, C>, String> {
+        let params = ModeParams::ofb(C::BLOCK_SIZE, s, z, iv)?;
+
+        Ok(StreamCipher::new(&self.keys, Mode::Ofb { s }, false, params.iv))
+    }
+
+    /// Потоковое зашифрование в режиме простой замены с зацеплением (см. cbc_encrypt)
+    pub fn cbc_encryptor(&self, z: usize, iv: &[u8], padding: Padding) -> Result<StreamCipher<'_, C>, String> {
+        let params = ModeParams::cbc(C::BLOCK_SIZE, z, iv)?;
+
+        Ok(StreamCipher::new(&self.keys, Mode::Cbc { padding }, false, params.iv))
+    }
+
+    /// Потоковое расшифрование в режиме простой замены с зацеплением (см. cbc_decrypt)
+    pub fn cbc_decryptor(&self, z: usize, iv: &[u8], padding: Padding) -> Result<StreamCipher<'_, C>, String> {
+        let params = ModeParams::cbc(C::BLOCK_SIZE, z, iv)?;
+
+        Ok(StreamCipher::new(&self.keys, Mode::Cbc { padding }, true, params.iv))
+    }
+
+    /// Потоковое зашифрование в режиме гаммирования с обратной связью по шифротексту (см. cfb_encrypt)
+    pub fn cfb_encryptor(&self, s: usize, z: usize, iv: &[u8]) -> Result<StreamCipher<'_, C>, String> {
+        let params = ModeParams::cfb(C::BLOCK_SIZE, s, z, iv)?;
+
+        Ok(StreamCipher::new(&self.keys, Mode::Cfb { s }, false, params.iv))
+    }
+
+    /// Потоковое расшифрование в режиме гаммирования с обратной связью по шифротексту (см. cfb_decrypt)
+    pub fn cfb_decryptor(&self, s: usize, z: usize, iv: &[u8]) -> Result<StreamCipher<'_, C>, String> {
+        let params = ModeParams::cfb(C::BLOCK_SIZE, s, z, iv)?;
+
+        Ok(StreamCipher::new(&self.keys, Mode::Cfb { s }, true, params.iv))
+    }
+}
+
+#[allow(dead_code)]
+impl<'a, C: BlockCipher> StreamCipher<'a, C> {
+    fn new(cipher: &'a C, mode: Mode, decrypt: bool, register: Vec<u8>) -> Self {
+        Self {
+            cipher,
+            mode,
+            decrypt,
+            offset: 0,
+            register,
+            gamma: vec![],
+            gamma_s: vec![],
+            completed: vec![],
+            pending: vec![],
+            finalized: false,
+        }
+    }
+
+    /// Обработка очередной части данных, результат дописывается в out.
+    /// В режимах ECB и CBC часть результата может быть выдана только при следующих вызовах.
+    pub fn update(&mut self, input: &[u8], out: &mut Vec<u8>) {
+        assert!(!self.finalized, "Поток уже завершен вызовом finalize");
+
+        match self.mode {
+            Mode::Ecb { .. } | Mode::Cbc { .. } => self.update_blocks(input, out),
+            Mode::Ctr { s, ref iv } => {
+                let start = out.len();
+                out.extend_from_slice(input);
+                ctr_apply_gamma(
+                    C::BLOCK_SIZE,
+                    &|block| encrypt_block(self.cipher, block),
+                    &mut out[start..],
+                    self.offset,
+                    s,
+                    iv,
+                );
+            }
+            Mode::Ofb { s } | Mode::Cfb { s } => {
+                for (idx, &byte) in input.iter().enumerate() {
+                    out.push(self.crypt_byte(self.offset + idx, byte, s));
+                }
+            }
+        }
+
+        self.offset += input.len();
+    }
+
+    /// Завершение потока: дополнение и шифрование последнего блока (ECB, CBC)
+    /// или расшифрование последнего блока с удалением дополнения.
+    pub fn finalize(&mut self, out: &mut Vec<u8>) -> Result<(), String> {
+        if self.finalized {
+            return Ok(());
+        }
+        self.finalized = true;
+
+        let padding = match self.mode {
+            Mode::Ecb { padding } | Mode::Cbc { padding } => padding,
+            _ => return Ok(()),
+        };
+
+        let n = C::BLOCK_SIZE;
+        let mut pending = std::mem::take(&mut self.pending);
+        let mut last = vec![];
+
+        let res = if !self.decrypt {
+            pad(&pending, n, padding).map(|padded| {
+                for block in padded.chunks(n) {
+                    last.extend_from_slice(&self.crypt_block(block));
+                }
+            })
+        } else if !pending.len().is_multiple_of(n) {
+            Err(format!("Длина должна быть кратна {} битам", n * 8))
+        } else {
+            for block in pending.chunks(n) {
+                last.extend_from_slice(&self.crypt_block(block));
+            }
+
+            unpad(&mut last, n, padding)
+        };
+
+        if res.is_ok() {
+            out.extend_from_slice(&last);
+        }
+
+        pending.zeroize();
+        last.zeroize();
+
+        res
+    }
+
+    /// Обертка для зашифрования (расшифрования) при записи в inner
+    pub fn writer<W: Write>(self, inner: W) -> CipherWriter<'a, C, W> {
+        CipherWriter {
+            stream: self,
+            inner,
+            buffer: vec![],
+        }
+    }
+
+    /// Обертка для зашифрования (расшифрования) при чтении из inner
+    pub fn reader<R: Read>(self, inner: R) -> CipherReader<'a, C, R> {
+        CipherReader {
+            stream: self,
+            inner,
+            chunk: vec![0u8; READ_CHUNK],
+            buffer: vec![],
+            pos: 0,
+            done: false,
+        }
+    }
+
+    // ECB, CBC: обработка целых блоков. При расшифровании последний блок
+    // придерживается до finalize, так как из него удаляется дополнение.
+    fn update_blocks(&mut self, input: &[u8], out: &mut Vec<u8>) {
+        let n = C::BLOCK_SIZE;
+        self.pending.extend_from_slice(input);
+
+        let keep = if self.decrypt {
+            match self.pending.len() % n {
+                0 => n.min(self.pending.len()),
+                rem => rem,
+            }
+        } else {
+            self.pending.len() % n
+        };
+
+        let ready = self.pending.len() - keep;
+        let blocks: Vec<u8> = self.pending.drain(..ready).collect();
+
+        for block in blocks.chunks(n) {
+            out.extend_from_slice(&self.crypt_block(block));
+        }
+    }
+
+    // Один блок ECB или CBC со сдвигом регистра R = LSB_[m-n](R) || C
+    fn crypt_block(&mut self, block: &[u8]) -> Vec<u8> {
+        let n = C::BLOCK_SIZE;
+        let mut res = block.to_vec();
+
+        match (&self.mode, self.decrypt) {
+            (Mode::Cbc { .. }, false) => {
+                let m = self.register.len();
+                res = sum_mod2_slice(&res, &self.register[m - n..]).unwrap();
+                self.cipher.encrypt_block(&mut res);
+                shift_register(&mut self.register, n, &res);
+            }
+            (Mode::Cbc { .. }, true) => {
+                let m = self.register.len();
+                self.cipher.decrypt_block(&mut res);
+                res = sum_mod2_slice(&res, &self.register[m - n..]).unwrap();
+                shift_register(&mut self.register, n, block);
+            }
+            (_, false) => self.cipher.encrypt_block(&mut res),
+            (_, true) => self.cipher.decrypt_block(&mut res),
+        }
+
+        res
+    }
+
+    // OFB, CFB: побитовая обработка байта. Бит p сообщения складывается с битом p % 8 байта
+    // (p / 8) % n гаммы шага p / s, регистр сдвигается перед началом каждого следующего шага.
+    fn crypt_byte(&mut self, byte_idx: usize, byte: u8, s: usize) -> u8 {
+        let n = C::BLOCK_SIZE;
+        let mut gamma_byte = 0;
+
+        for bit in 0..8 {
+            let p = byte_idx * 8 + bit;
+
+            if p.is_multiple_of(s) {
+                if p > 0 {
+                    self.shift();
+                }
+
+                let m = self.register.len();
+                self.gamma.zeroize();
+                self.gamma_s.zeroize();
+                self.gamma = encrypt_block(self.cipher, &self.register[m - n..]);
+                self.gamma_s = msb(&self.gamma, s);
+            }
+
+            gamma_byte |= self.gamma_s[byte_idx % n] & (1 << bit);
+        }
+
+        let res = byte ^ gamma_byte;
+
+        // Байт шифротекста для регистра CFB
+        if let Mode::Cfb { .. } = self.mode {
+            self.completed.push(if self.decrypt { byte } else { res });
+        }
+
+        res
+    }
+
+    // Сдвиг регистра после шага: OFB - R = LSB_[m-n](R) || e_k(MSB_n(R)),
+    // CFB - сдвиг на s бит с занесением байтов шифротекста, завершенных на шаге
+    fn shift(&mut self) {
+        match self.mode {
+            Mode::Ofb { .. } => shift_register(&mut self.register, C::BLOCK_SIZE, &self.gamma),
+            Mode::Cfb { s } => {
+                shift_register_bits(&mut self.register, s);
+                for (r, c) in self.register.iter_mut().zip(&self.completed) {
+                    *r |= c;
+                }
+                self.completed.clear();
+            }
+            _ => {}
+        }
+    }
+}
+
+impl<C: BlockCipher> Drop for StreamCipher<'_, C> {
+    fn drop(&mut self) {
+        self.register.zeroize();
+        self.gamma.zeroize();
+        self.gamma_s.zeroize();
+        self.completed.zeroize();
+        self.pending.zeroize();
+    }
+}
+
+/// Запись с зашифрованием (расшифрованием): данные, записанные в обертку, обрабатываются
+/// StreamCipher и записываются в inner. После записи всех данных нужно вызвать finish.
+pub struct CipherWriter<'a, C: BlockCipher, W: Write> {
+    stream: StreamCipher<'a, C>,
+    inner: W,
+    buffer: Vec<u8>,
+}
+
+#[allow(dead_code)]
+impl<C: BlockCipher, W: Write> CipherWriter<'_, C, W> {
+    /// Обработка последнего блока и возврат inner
+    pub fn finish(mut self) -> io::Result<W> {
+        self.buffer.clear();
+        self.stream.finalize(&mut self.buffer).map_err(invalid_data)?;
+        self.inner.write_all(&self.buffer)?;
+        self.inner.flush()?;
+        self.buffer.zeroize();
+
+        Ok(self.inner)
+    }
+}
+
+impl<C: BlockCipher, W: Write> Write for CipherWriter<'_, C, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.clear();
+        self.stream.update(buf, &mut self.buffer);
+        self.inner.write_all(&self.buffer)?;
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Чтение с зашифрованием (расшифрованием): данные читаются из inner порциями
+/// и обрабатываются StreamCipher, по достижении конца inner вызывается finalize.
+pub struct CipherReader<'a, C: BlockCipher, R: Read> {
+    stream: StreamCipher<'a, C>,
+    inner: R,
+    // Порция, прочитанная из inner, выделяется один раз
+    chunk: Vec<u8>,
+    buffer: Vec<u8>,
+    pos: usize,
+    done: bool,
+}
+
+impl<C: BlockCipher, R: Read> Read for CipherReader<'_, C, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.pos == self.buffer.len() && !self.done {
+            self.buffer.zeroize();
+            self.buffer.clear();
+            self.pos = 0;
+
+            let read = self.inner.read(&mut self.chunk)?;
+            if read == 0 {
+                self.done = true;
+                self.stream.finalize(&mut self.buffer).map_err(invalid_data)?;
+            } else {
+                self.stream.update(&self.chunk[..read], &mut self.buffer);
+            }
+        }
+
+        let len = buf.len().min(self.buffer.len() - self.pos);
+        buf[..len].copy_from_slice(&self.buffer[self.pos..self.pos + len]);
+        self.pos += len;
+
+        Ok(len)
+    }
+}
+
+impl<C: BlockCipher, R: Read> Drop for CipherReader<'_, C, R> {
+    fn drop(&mut self) {
+        self.chunk.zeroize();
+        self.buffer.zeroize();
+    }
+}
+
+fn invalid_data(msg: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{kuznechik::Kuznechik, magma::Magma, random_vec};
+
+    // Обработка data частями случайной длины через update
+    fn by_parts<C: BlockCipher>(mut stream: StreamCipher<C>, data: &[u8]) -> Result<Vec<u8>, String> {
+        let mut out = vec![];
+        let mut rest = data;
+
+        while !rest.is_empty() {
+            let len = (random_vec(1)[0] as usize % 23).min(rest.len());
+            stream.update(&rest[..len], &mut out);
+            rest = &rest[len..];
+        }
+
+        stream.finalize(&mut out)?;
+
+        Ok(out)
+    }
+
+    #[test]
+    fn test_stream_matches_one_shot() {
+        let kuz = CipherModes::new(Kuznechik::new());
+        let magma = CipherModes::new(Magma::new());
+
+        for len in [0, 1, 7, 8, 16, 17, 100, 333] {
+            let p = random_vec(len);
+            let iv = random_vec(48);
+
+            for padding in [Padding::None, Padding::Procedure1, Padding::Procedure2, Padding::Procedure3] {
+                let c = kuz.ecb_encrypt(&p, padding).map(|c| c.concat());
+                assert_eq!(by_parts(kuz.ecb_encryptor(padding), &p), c);
+
+                let c = c.unwrap_or_default();
+                assert_eq!(by_parts(kuz.ecb_decryptor(padding), &c), kuz.ecb_decrypt(&c, padding));
+
+                let c = kuz.cbc_encrypt(&p, 3, &iv, padding);
+                assert_eq!(by_parts(kuz.cbc_encryptor(3, &iv, padding).unwrap(), &p), c);
+
+                let c = c.unwrap_or_default();
+                let stream = kuz.cbc_decryptor(3, &iv, padding).unwrap();
+                assert_eq!(by_parts(stream, &c), kuz.cbc_decrypt(&c, 3, &iv, padding));
+            }
+
+            // Все режимы гаммирования, в том числе при s, не кратных 8
+            for s in [1, 3, 8, 13, 64, 100, 128] {
+                let stream = kuz.ctr_stream(s, &iv[..8]).unwrap();
+                assert_eq!(by_parts(stream, &p).unwrap(), kuz.ctr_crypt(&p, s, &iv[..8]).unwrap(), "CTR s = {}", s);
+
+                for z in [1, 2] {
+                    let iv = &iv[..16 * z];
+
+                    let stream = kuz.ofb_stream(s, z, iv).unwrap();
+                    assert_eq!(by_parts(stream, &p).unwrap(), kuz.ofb_crypt(&p, s, z, iv).unwrap(), "OFB s = {}", s);
+
+                    let c = kuz.cfb_encrypt(&p, s, z, iv).unwrap();
+                    assert_eq!(by_parts(kuz.cfb_encryptor(s, z, iv).unwrap(), &p).unwrap(), c, "CFB s = {}", s);
+                    assert_eq!(by_parts(kuz.cfb_decryptor(s, z, iv).unwrap(), &c).unwrap(), p);
+                }
+            }
+
+            for s in [5, 32, 64] {
+                let c = magma.cfb_encrypt(&p, s, 3, &iv[..24]).unwrap();
+                assert_eq!(by_parts(magma.cfb_encryptor(s, 3, &iv[..24]).unwrap(), &p).unwrap(), c);
+            }
+        }
+
+        // Неверные параметры и длина шифротекста
+        assert!(kuz.ctr_stream(0, &[0; 8]).is_err());
+        assert!(kuz.ofb_stream(8, 1, &[0; 15]).is_err());
+        assert!(kuz.cbc_decryptor(0, &[], Padding::None).is_err());
+        assert!(by_parts(kuz.ecb_decryptor(Padding::None), &random_vec(20)).is_err());
+    }
+
+    #[test]
+    fn test_stream_reader_writer() {
+        let kuz = CipherModes::new(Kuznechik::new());
+        let iv = random_vec(32);
+        let p = random_vec(200_003);
+
+        // Запись частями в Vec
+        let mut writer = kuz.cbc_encryptor(2, &iv, Padding::Procedure2).unwrap().writer(vec![]);
+        for part in p.chunks(1000) {
+            writer.write_all(part).unwrap();
+        }
+        let c = writer.finish().unwrap();
+        assert_eq!(c, kuz.cbc_encrypt(&p, 2, &iv, Padding::Procedure2).unwrap());
+
+        // Чтение с расшифрованием
+        let mut res = vec![];
+        let reader = kuz.cbc_decryptor(2, &iv, Padding::Procedure2).unwrap().reader(&c[..]);
+        reader.take(u64::MAX).read_to_end(&mut res).unwrap();
+        assert_eq!(res, p);
+
+        let mut res = vec![];
+        kuz.cfb_encryptor(8, 2, &iv).unwrap().reader(&p[..1000]).read_to_end(&mut res).unwrap();
+        assert_eq!(res, kuz.cfb_encrypt(&p[..1000], 8, 2, &iv).unwrap());
+
+        // Ошибка длины шифротекста при чтении
+        let mut res = vec![];
+        assert!(kuz.ecb_decryptor(Padding::None).reader(&c[..c.len() - 1]).read_to_end(&mut res).is_err());
+    }
+}
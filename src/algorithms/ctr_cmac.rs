@@ -0,0 +1,125 @@
+use super::{
+    block_cipher_modes::{kdf_cmac, CipherModes, CMAC},
+    kuznechik::Kuznechik,
+    random_vec, FromMasterKey,
+};
+
+// Метка выработки ключей, входит в каждый блок KDF
+const KDF_LABEL: &[u8] = b"CTR-CMAC";
+
+/// Аутентифицированное шифрование по схеме "зашифровать, затем вычислить имитовставку"
+/// (encrypt-then-MAC): режим гаммирования CTR (s = n) и CMAC длины n бит на разных ключах,
+/// выработанных из ключа шифра. Результат шифрования - IV (n/2 байт) || C || T (n байт),
+/// имитовставка T вычисляется по IV || C.
+#[allow(dead_code)]
+pub struct CtrCmac<C: FromMasterKey = Kuznechik> {
+    modes: CipherModes<C>,
+    cmac: CMAC<C>,
+}
+
+#[allow(dead_code)]
+impl<C: FromMasterKey> CtrCmac<C> {
+    /// Выработка ключа шифрования K_enc и ключа имитозащиты K_mac из ключа шифра cipher
+    /// (см. kdf_cmac). Сам ключ cipher для данных не используется.
+    pub fn new(cipher: C) -> Result<Self, String> {
+        let (k_enc, k_mac) = kdf_cmac(cipher, KDF_LABEL)?;
+
+        Ok(Self {
+            modes: CipherModes::new(k_enc),
+            cmac: CMAC::new(k_mac)?,
+        })
+    }
+
+    /// Зашифрование plaintext на случайном IV
+    pub fn encrypt(&self, plaintext: &[u8]) -> Result<Vec<u8>, String> {
+        self.encrypt_with_iv(&random_vec(C::BLOCK_SIZE / 2), plaintext)
+    }
+
+    /// Зашифрование plaintext на IV длины n/2 байт, который не должен повторяться для одного ключа
+    pub fn encrypt_with_iv(&self, iv: &[u8], plaintext: &[u8]) -> Result<Vec<u8>, String> {
+        let mut res = iv.to_vec();
+        res.extend(self.modes.ctr_crypt(plaintext, C::BLOCK_SIZE * 8, iv)?);
+
+        let tag = self.cmac.cmac(&res, C::BLOCK_SIZE * 8)?;
+        res.extend(tag);
+
+        Ok(res)
+    }
+
+    /// Проверка имитовставки и расшифрование IV || C || T. Если имитовставка не совпадает,
+    /// открытый текст не вычисляется.
+    pub fn decrypt(&self, data: &[u8]) -> Result<Vec<u8>, String> {
+        let n = C::BLOCK_SIZE;
+
+        if data.len() < n / 2 + n {
+            return Err("Данные короче IV и имитовставки".to_string());
+        }
+
+        let (authenticated, tag) = data.split_at(data.len() - n);
+        if !self.cmac.verify(authenticated, tag)? {
+            return Err("Имитовставка не совпадает: данные изменены или ключ другой".to_string());
+        }
+
+        let (iv, ciphertext) = authenticated.split_at(n / 2);
+        self.modes.ctr_crypt(ciphertext, n * 8, iv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{
+        kuznechik::KuznechikKey,
+        magma::{Magma, MagmaKey},
+    };
+
+    #[test]
+    fn test_ctr_cmac_roundtrip_and_tamper() {
+        let key = KuznechikKey::generate();
+        let aead = CtrCmac::new(Kuznechik::with_key(key.clone_secret())).unwrap();
+
+        for len in [0, 1, 15, 16, 17, 100] {
+            let p = random_vec(len);
+            let c = aead.encrypt(&p).unwrap();
+
+            assert_eq!(c.len(), 8 + len + 16);
+            assert_eq!(aead.decrypt(&c).unwrap(), p);
+
+            // Изменение любого байта IV, шифротекста или имитовставки отклоняется
+            for idx in 0..c.len() {
+                let mut bad = c.clone();
+                bad[idx] ^= 1 << (idx % 8);
+                assert!(aead.decrypt(&bad).is_err(), "байт {}", idx);
+            }
+
+            assert!(aead.decrypt(&c[..c.len() - 1]).is_err());
+        }
+        assert!(aead.decrypt(&random_vec(23)).is_err());
+
+        // Ключи шифрования и имитозащиты отличаются от ключа шифра
+        let p = random_vec(48);
+        let iv = random_vec(8);
+        let c = aead.encrypt_with_iv(&iv, &p).unwrap();
+        let plain_ctr = CipherModes::new(Kuznechik::with_key(key.clone_secret())).ctr_crypt(&p, 128, &iv).unwrap();
+        assert_ne!(c[8..56], plain_ctr);
+
+        // Тот же ключ - тот же результат, другой ключ - ошибка проверки
+        let same = CtrCmac::new(Kuznechik::with_key(key)).unwrap();
+        assert_eq!(same.encrypt_with_iv(&iv, &p).unwrap(), c);
+        assert!(CtrCmac::new(Kuznechik::new()).unwrap().decrypt(&c).is_err());
+        assert!(aead.encrypt_with_iv(&iv[1..], &p).is_err());
+    }
+
+    #[test]
+    fn test_ctr_cmac_magma() {
+        let aead = CtrCmac::new(Magma::with_key(MagmaKey::generate())).unwrap();
+        let p = random_vec(37);
+
+        let mut c = aead.encrypt(&p).unwrap();
+        assert_eq!(c.len(), 4 + 37 + 8);
+        assert_eq!(aead.decrypt(&c).unwrap(), p);
+
+        c[10] ^= 0x80;
+        assert!(aead.decrypt(&c).is_err());
+    }
+}
@@ -0,0 +1,165 @@
+use zeroize::Zeroize;
+
+use super::{
+    kuznechik::{Kuznechik, KuznechikKey},
+    secret::Secret,
+    streebog::hmac::HmacStreebog256,
+};
+
+// Длина выхода HMAC_GOSTR3411_2012_256 в байтах
+const H_LEN: usize = 32;
+
+/// KDF_GOSTR3411_2012_256 (Р 50.1.113-2016, 4.4): 256 бит из ключа key,
+/// K = HMAC256(key, 0x01 || label || 0x00 || seed || 0x01 || 0x00).
+/// Ключ, метка, seed и результат - строки байтов в порядке передачи.
+pub fn kdf_256(key: &[u8], label: &[u8], seed: &[u8]) -> Secret<Vec<u8>> {
+    let mut hmac = HmacStreebog256::new(key);
+    hmac.update(&[0x01]);
+    hmac.update(label);
+    hmac.update(&[0x00]);
+    hmac.update(seed);
+    hmac.update(&[0x01, 0x00]);
+
+    Secret::new(hmac.finalize())
+}
+
+/// KDF_TREE_GOSTR3411_2012_256 (Р 50.1.113-2016, 4.5): len байт из ключа key,
+/// K(i) = HMAC256(key, [i]_r || label || 0x00 || seed || [L]), где номер блока i записан в r байтах
+/// big-endian (1 <= r <= 4), а L = 8 * len - длина результата в битах без ведущих нулевых байтов.
+pub fn kdf_tree_256(key: &[u8], label: &[u8], seed: &[u8], r: usize, len: usize) -> Result<Secret<Vec<u8>>, String> {
+    if !(1..=4).contains(&r) {
+        return Err("Параметр R KDF_TREE должен быть от 1 до 4 байт".to_string());
+    }
+
+    let blocks = len.div_ceil(H_LEN);
+    if len < 1 || blocks as u64 >= 1 << (8 * r) {
+        return Err("Некорректная длина ключа KDF_TREE для заданного R".to_string());
+    }
+
+    let l = ((len as u64) * 8).to_be_bytes();
+    let l = &l[l.iter().take_while(|byte| **byte == 0).count()..];
+
+    // Ключ HMAC обрабатывается один раз, для каждого блока копируется готовое состояние
+    let prf = HmacStreebog256::new(key);
+    let mut res = Secret::new(Vec::with_capacity(blocks * H_LEN));
+
+    for idx in 1..=blocks as u32 {
+        let mut hmac = prf.clone();
+        hmac.update(&idx.to_be_bytes()[4 - r..]);
+        hmac.update(label);
+        hmac.update(&[0x00]);
+        hmac.update(seed);
+        hmac.update(l);
+
+        let mut block = hmac.finalize();
+        res.expose_mut().extend_from_slice(&block);
+        block.zeroize();
+    }
+
+    res.expose_mut().truncate(len);
+
+    Ok(res)
+}
+
+/// Выработка ключа Кузнечика с меткой label из мастер-ключа шифра master (KDF_GOSTR3411_2012_256).
+/// Ключи, как и в KuznechikKey::from_hex, - строки байтов в big-endian.
+#[allow(dead_code)]
+pub fn derive_kuznechik(master: &Kuznechik, label: &[u8], seed: &[u8]) -> Kuznechik {
+    let mut key = master_string(master);
+    let mut derived = kdf_256(&key, label, seed);
+    key.zeroize();
+
+    derived.expose_mut().reverse();
+    Kuznechik::with_key(KuznechikKey::try_from(&derived.expose()[..]).expect("KDF_256 вырабатывает 32 байта"))
+}
+
+/// Выработка count ключей Кузнечика с меткой label из мастер-ключа шифра master (KDF_TREE_GOSTR3411_2012_256, R = 1).
+/// Например, ключ шифрования, ключ имитозащиты и ключи отдельных файлов.
+#[allow(dead_code)]
+pub fn derive_kuznechik_keys(master: &Kuznechik, label: &[u8], seed: &[u8], count: usize) -> Result<Vec<Kuznechik>, String> {
+    let mut key = master_string(master);
+    let derived = kdf_tree_256(&key, label, seed, 1, count * 32);
+    key.zeroize();
+
+    derived?
+        .expose()
+        .chunks(32)
+        .map(|chunk| {
+            let mut bytes = chunk.to_vec();
+            bytes.reverse();
+            let key = KuznechikKey::try_from(&bytes[..]);
+            bytes.zeroize();
+
+            Ok(Kuznechik::with_key(key?))
+        })
+        .collect()
+}
+
+// Мастер-ключ как строка байтов: master_key хранится в little-endian
+fn master_string(master: &Kuznechik) -> Vec<u8> {
+    master.keys.master_key().iter().rev().copied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{hex_to_bytes, streebog::hmac::hmac, to_hex};
+
+    // Р 50.1.113-2016, примеры 4.4 и 4.5
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    const LABEL: &str = "26bdb878";
+    const SEED: &str = "af21434145656378";
+
+    #[test]
+    fn test_kdf_gost() {
+        let (key, label, seed) = (hex_to_bytes(KEY), hex_to_bytes(LABEL), hex_to_bytes(SEED));
+
+        assert_eq!(
+            kdf_256(&key, &label, &seed).expose(),
+            &hex_to_bytes("a1aa5f7de402d7b3d323f2991c8d4534013137010a83754fd0af6d7cd4922ed9")
+        );
+
+        // KDF_TREE с L = 512, R = 1: K1 из примера, K2 по определению
+        let tree = kdf_tree_256(&key, &label, &seed, 1, 64).unwrap();
+        assert_eq!(
+            tree.expose()[..32],
+            hex_to_bytes("22b6837845c6bef65ea71672b265831086d3c76aebe6dae91cad51d83f79d16b")
+        );
+        let k2 = hmac(&key, &[&[0x02], &label[..], &[0x00], &seed[..], &[0x02, 0x00]].concat(), 256).unwrap();
+        assert_eq!(tree.expose()[32..], k2);
+
+        // KDF_256 совпадает с KDF_TREE при L = 256, R = 1
+        assert_eq!(kdf_tree_256(&key, &label, &seed, 1, 32).unwrap().expose(), kdf_256(&key, &label, &seed).expose());
+
+        // Длина результата входит в каждый блок: префиксы разной длины различаются
+        assert_ne!(kdf_tree_256(&key, &label, &seed, 1, 16).unwrap().expose()[..], tree.expose()[..16]);
+        assert_ne!(kdf_tree_256(&key, &label, &seed, 2, 64).unwrap().expose(), tree.expose());
+
+        assert!(kdf_tree_256(&key, &label, &seed, 0, 32).is_err());
+        assert!(kdf_tree_256(&key, &label, &seed, 5, 32).is_err());
+        assert!(kdf_tree_256(&key, &label, &seed, 1, 0).is_err());
+        assert!(kdf_tree_256(&key, &label, &seed, 1, 256 * 32).is_err());
+    }
+
+    #[test]
+    fn test_derive_kuznechik_keys() {
+        let master = Kuznechik::with_key(KuznechikKey::from_hex(KEY).unwrap());
+        let label = hex_to_bytes(LABEL);
+        let seed = hex_to_bytes(SEED);
+
+        let derived = derive_kuznechik(&master, &label, &seed);
+        assert_eq!(to_hex(derived.keys.master_key()), "A1AA5F7DE402D7B3D323F2991C8D4534013137010A83754FD0AF6D7CD4922ED9");
+
+        let keys = derive_kuznechik_keys(&master, &label, &seed, 3).unwrap();
+        assert_eq!(keys.len(), 3);
+        let tree = kdf_tree_256(&hex_to_bytes(KEY), &label, &seed, 1, 96).unwrap();
+        for (key, block) in keys.iter().zip(tree.expose().chunks(32)) {
+            assert_eq!(to_hex(key.keys.master_key()), hex::encode_upper(block));
+        }
+
+        // Разные метки и разные номера дают разные ключи
+        assert_ne!(keys[0].keys.master_key(), keys[1].keys.master_key());
+        assert_ne!(derive_kuznechik(&master, b"mac", &seed).keys.master_key(), derived.keys.master_key());
+        assert_ne!(keys[0].keys.master_key(), master.keys.master_key());
+    }
+}
@@ -0,0 +1,453 @@
+use std::{
+    fmt::Write as _,
+    fs,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use zeroize::Zeroize;
+
+use super::{
+    block_cipher_modes::{CipherModes, CMAC},
+    kuznechik::{Kuznechik, KuznechikKey},
+    pbkdf2::pbkdf2,
+    random_vec,
+    secret::Secret,
+    streebog::streebog,
+    to_hex,
+};
+
+/// Версия формата файла ключа
+pub const KEY_FILE_VERSION: u32 = 1;
+
+/// Метаданные, сохраняемые в файле ключа вместе с мастер-ключом:
+/// время создания (Unix time, секунды), логин владельца и параметры режимов S, Z, IV.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeyFileMeta {
+    pub created: u64,
+    pub owner: String,
+    pub s: u32,
+    pub z: u32,
+    pub iv: Vec<u8>,
+}
+
+#[allow(dead_code)]
+impl KeyFileMeta {
+    /// Метаданные с текущим временем создания
+    pub fn new(owner: &str, s: u32, z: u32, iv: &[u8]) -> Self {
+        let created = match SystemTime::now().duration_since(UNIX_EPOCH) {
+            Ok(time) => time.as_secs(),
+            Err(_) => 0,
+        };
+
+        Self {
+            created,
+            owner: owner.to_string(),
+            s,
+            z,
+            iv: iv.to_vec(),
+        }
+    }
+}
+
+// Порядок строк файла. Последней строкой идет CHECKSUM - хэш-код Стрибог-256
+// всех предыдущих строк, поэтому обрезанный или измененный файл не загрузится.
+// Мастер-ключ записывается либо открыто (K), либо зашифрованным на пароле. В защищенном
+// файле K_MAC - имитовставка всех остальных строк, кроме CHECKSUM, на ключе из пароля.
+const HEADER: [&str; 4] = ["VERSION", "ALGORITHM", "CREATED", "OWNER"];
+const PLAIN_KEY: [&str; 1] = ["K"];
+const PROTECTED_KEY: [&str; 4] = ["SALT", "ITERATIONS", "K_ENC", "K_MAC"];
+const PARAMS: [&str; 3] = ["S", "Z", "IV"];
+
+/// Число итераций PBKDF2 при записи файла
+pub const KDF_ITERATIONS: u32 = 100_000;
+
+// Ограничение числа итераций при чтении файла
+const KDF_MAX_ITERATIONS: u32 = 10_000_000;
+
+/// Запись файла ключа версии KEY_FILE_VERSION для алгоритма algorithm с длиной блока n байт.
+/// Итерационные ключи не записываются, они вычисляются из мастер-ключа при загрузке.
+/// Если передан пароль, мастер-ключ записывается зашифрованным на ключе, выработанном из пароля по PBKDF2.
+pub fn write_key_file(
+    path: &Path,
+    algorithm: &str,
+    n: usize,
+    master: &[u8; 32],
+    meta: &KeyFileMeta,
+    password: Option<&str>,
+) -> Result<PathBuf, String> {
+    let text = key_file_text(algorithm, n, master, meta, password.map(|password| (password, KDF_ITERATIONS)))?;
+
+    match fs::write(path, text.expose()) {
+        Ok(_) => Ok(path.to_path_buf()),
+        Err(_) => Err("Ошибка создания файла".to_string()),
+    }
+}
+
+/// Загрузка файла ключа алгоритма algorithm с длиной блока n байт. Отклоняет файлы
+/// другой версии или другого алгоритма, обрезанные, измененные (не совпадает контрольная
+/// сумма), с некорректными значениями, а также защищенные файлы при неверном пароле.
+/// Возвращает мастер-ключ и метаданные.
+pub fn read_key_file(
+    path: &Path,
+    algorithm: &str,
+    n: usize,
+    password: Option<&str>,
+) -> Result<(Secret<[u8; 32]>, KeyFileMeta), String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => Secret::new(text),
+        Err(_) => return Err("Ошибка открытия файла с ключами".to_string()),
+    };
+
+    parse_key_file(text.expose(), algorithm, n, password)
+}
+
+/// Генерация случайного мастер-ключа K
+pub fn random_master_key() -> Secret<[u8; 32]> {
+    let mut k = random_vec(32);
+    let key = master_key_from_slice(&k);
+    k.zeroize();
+
+    key.expect("random_vec(32) возвращает 32 байта")
+}
+
+/// Мастер-ключ K из строки из 64 hex-символов в том виде, в котором ключ
+/// отображается в GUI и записывается в файл (big-endian).
+pub fn master_key_from_hex(hex: &str) -> Result<Secret<[u8; 32]>, String> {
+    let mut bytes = match hex::decode(hex.trim()) {
+        Ok(bytes) => bytes,
+        Err(_) => return Err("Ключ должен состоять из hex-символов".to_string()),
+    };
+    bytes.reverse();
+
+    let key = master_key_from_slice(&bytes);
+    bytes.zeroize();
+
+    key
+}
+
+/// Мастер-ключ K из среза байтов в little-endian с проверкой длины (32 байта)
+pub fn master_key_from_slice(key: &[u8]) -> Result<Secret<[u8; 32]>, String> {
+    if key.len() != 32 {
+        return Err(format!("Длина ключа должна быть 32 байта, передано {}", key.len()));
+    }
+
+    let mut master = Secret::new([0u8; 32]);
+    master.expose_mut().copy_from_slice(key);
+
+    Ok(master)
+}
+
+// Формирование содержимого файла ключа, password - пароль и число итераций KDF
+fn key_file_text(
+    algorithm: &str,
+    n: usize,
+    master: &[u8; 32],
+    meta: &KeyFileMeta,
+    password: Option<(&str, u32)>,
+) -> Result<Secret<String>, String> {
+    check_meta(n, meta)?;
+
+    if meta.owner.contains(['\n', '\r']) {
+        return Err("Логин владельца не может содержать перевод строки".to_string());
+    }
+
+    // Ключи из пароля вырабатываются один раз: K_ENC пишется сразу, K_MAC - после всех строк
+    let protection = match password {
+        Some((password, iterations)) => {
+            let salt = random_vec(16);
+            let (modes, cmac) = password_keys(password, &salt, iterations)?;
+            Some((salt, iterations, modes, cmac))
+        }
+        None => None,
+    };
+
+    let mut lines: Vec<(&str, String)> = vec![
+        ("VERSION", KEY_FILE_VERSION.to_string()),
+        ("ALGORITHM", algorithm.to_string()),
+        ("CREATED", meta.created.to_string()),
+        ("OWNER", meta.owner.clone()),
+    ];
+
+    match &protection {
+        None => lines.push(("K", to_hex(master))),
+        Some((salt, iterations, modes, _)) => {
+            lines.push(("SALT", to_hex(salt)));
+            lines.push(("ITERATIONS", iterations.to_string()));
+            lines.push(("K_ENC", to_hex(&modes.ctr_crypt(master, 128, &salt[..8])?)));
+        }
+    }
+
+    lines.push(("S", meta.s.to_string()));
+    lines.push(("Z", meta.z.to_string()));
+    lines.push(("IV", to_hex(&meta.iv)));
+
+    if let Some((_, _, _, cmac)) = &protection {
+        let pairs: Vec<(&str, &str)> = lines.iter().map(|(name, value)| (*name, value.as_str())).collect();
+        let k_mac = cmac.cmac(&mac_data(&pairs), 128)?;
+
+        lines.insert(HEADER.len() + PROTECTED_KEY.len() - 1, ("K_MAC", to_hex(&k_mac)));
+    }
+
+    let mut text = Secret::new(String::new());
+
+    for (name, mut value) in lines {
+        let res = writeln!(text.expose_mut(), "{} = {}", name, value);
+        value.zeroize();
+
+        if res.is_err() {
+            return Err("Ошибка записи ключей".to_string());
+        }
+    }
+
+    let checksum = checksum(text.expose())?;
+    if writeln!(text.expose_mut(), "CHECKSUM = {}", checksum).is_err() {
+        return Err("Ошибка записи ключей".to_string());
+    }
+
+    Ok(text)
+}
+
+// Разбор и проверка содержимого файла ключа
+fn parse_key_file(
+    text: &str,
+    algorithm: &str,
+    n: usize,
+    password: Option<&str>,
+) -> Result<(Secret<[u8; 32]>, KeyFileMeta), String> {
+    // Контрольная сумма - последняя строка, она считается по тексту до нее
+    let body_len = match text.trim_end_matches('\n').rfind('\n') {
+        Some(idx) => idx + 1,
+        None => return Err("Файл с ключами обрезан: отсутствует контрольная сумма".to_string()),
+    };
+    let (body, checksum_line) = text.split_at(body_len);
+
+    match parse_line(checksum_line.trim_end_matches('\n')) {
+        Some(("CHECKSUM", value)) => {
+            if !value.eq_ignore_ascii_case(&checksum(body)?) {
+                return Err("Файл с ключами поврежден или изменен: контрольная сумма не совпадает".to_string());
+            }
+        }
+        _ => return Err("Файл с ключами обрезан: отсутствует контрольная сумма".to_string()),
+    }
+
+    let mut values: Vec<(&str, &str)> = vec![];
+    for line in body.lines() {
+        match parse_line(line) {
+            Some(pair) => values.push(pair),
+            None => return Err("Некорректный файл с ключами".to_string()),
+        }
+    }
+
+    // Версия проверяется до состава строк: в других версиях он может отличаться
+    match values.first() {
+        Some(("VERSION", version)) if *version == KEY_FILE_VERSION.to_string() => {}
+        Some(("VERSION", version)) => return Err(format!("Неподдерживаемая версия файла с ключами {}", version)),
+        _ => return Err("Некорректный файл с ключами: отсутствует версия".to_string()),
+    }
+
+    // Строки идут строго в порядке HEADER, ключ (открытый или защищенный), PARAMS
+    let names: Vec<&str> = values.iter().map(|(name, _)| *name).collect();
+    let protected = names == [&HEADER[..], &PROTECTED_KEY[..], &PARAMS[..]].concat();
+    if !protected && names != [&HEADER[..], &PLAIN_KEY[..], &PARAMS[..]].concat() {
+        return Err("Некорректный файл с ключами: неверный набор строк".to_string());
+    }
+
+    let value = |name: &str| values.iter().find(|(field, _)| *field == name).map_or("", |(_, value)| *value);
+
+    if value("ALGORITHM") != algorithm {
+        return Err(format!("Файл содержит ключ алгоритма {}, а не {}", value("ALGORITHM"), algorithm));
+    }
+
+    let mut key = if protected {
+        let password = match password {
+            Some(password) => password,
+            None => return Err("Файл с ключами защищен паролем, введите пароль".to_string()),
+        };
+
+        let salt = decode_hex(value("SALT"), "SALT")?;
+        let iterations: u32 = parse_number(value("ITERATIONS"), "ITERATIONS")?;
+        let k_enc = decode_hex(value("K_ENC"), "K_ENC")?;
+        let k_mac = decode_hex(value("K_MAC"), "K_MAC")?;
+
+        if !(1..=KDF_MAX_ITERATIONS).contains(&iterations) || salt.len() != 16 || k_enc.len() != 32 || k_mac.len() != 16 {
+            return Err("Некорректный файл с ключами".to_string());
+        }
+
+        // Неверный пароль дает другой ключ имитозащиты, поэтому имитовставка не совпадет.
+        // Она же защищает остальные строки, в том числе ITERATIONS, от подмены с пересчетом CHECKSUM.
+        let (modes, cmac) = password_keys(password, &salt, iterations)?;
+        let pairs: Vec<(&str, &str)> = values.iter().filter(|(name, _)| *name != "K_MAC").copied().collect();
+        if !cmac.verify(&mac_data(&pairs), &k_mac)? {
+            return Err("Неверный пароль от файла с ключами или файл изменен".to_string());
+        }
+
+        modes.ctr_crypt(&k_enc, 128, &salt[..8])?
+    } else {
+        // Пароль к незащищенному файлу означает, что файл подменен или выбран не тот файл
+        if password.is_some() {
+            return Err("Файл с ключами не защищен паролем, а пароль введен".to_string());
+        }
+
+        decode_hex(value("K"), "K")?
+    };
+
+    let master = master_key_from_slice(&key);
+    key.zeroize();
+    let master = master?;
+
+    let meta = KeyFileMeta {
+        created: parse_number(value("CREATED"), "CREATED")?,
+        owner: value("OWNER").to_string(),
+        s: parse_number(value("S"), "S")?,
+        z: parse_number(value("Z"), "Z")?,
+        iv: decode_hex(value("IV"), "IV")?,
+    };
+
+    check_meta(n, &meta)?;
+
+    Ok((master, meta))
+}
+
+// Выработка 512 бит из пароля и соли по PBKDF2 с HMAC_GOSTR3411_2012_512. Первые 256 бит -
+// ключ шифрования мастер-ключа в режиме CTR, последние - ключ CMAC. Соль случайная для каждого
+// файла, поэтому ключ шифрования одноразовый и в качестве IV берутся первые 8 байт соли.
+fn password_keys(password: &str, salt: &[u8], iterations: u32) -> Result<(CipherModes, CMAC), String> {
+    let dk = pbkdf2(password.as_bytes(), salt, iterations, 64)?;
+
+    let modes = CipherModes::new(Kuznechik::with_key(KuznechikKey::try_from(&dk.expose()[..32])?));
+    let cmac = CMAC::new(Kuznechik::with_key(KuznechikKey::try_from(&dk.expose()[32..])?))?;
+
+    Ok((modes, cmac))
+}
+
+// Данные, защищаемые имитовставкой K_MAC: строки "ИМЯ = значение" в порядке файла
+fn mac_data(lines: &[(&str, &str)]) -> Vec<u8> {
+    lines.iter().flat_map(|(name, value)| format!("{} = {}\n", name, value.trim()).into_bytes()).collect()
+}
+
+// Согласованность параметров режимов с длиной блока n байт
+fn check_meta(n: usize, meta: &KeyFileMeta) -> Result<(), String> {
+    if meta.s < 1 || meta.s as usize > n * 8 {
+        return Err(format!("Параметр S должен быть в диапазоне [1; {}]", n * 8));
+    }
+
+    if meta.z < 1 || meta.iv.len() != n * meta.z as usize {
+        return Err(format!("Длина IV должна быть равна {} * Z байт", n));
+    }
+
+    Ok(())
+}
+
+// Строка вида "ИМЯ = значение"
+fn parse_line(line: &str) -> Option<(&str, &str)> {
+    line.split_once('=').map(|(name, value)| (name.trim(), value.trim()))
+}
+
+fn parse_number<T: std::str::FromStr>(value: &str, name: &str) -> Result<T, String> {
+    match value.parse::<T>() {
+        Ok(value) => Ok(value),
+        Err(_) => Err(format!("Некорректное значение {} в файле с ключами", name)),
+    }
+}
+
+// hex-строка в big-endian -> байты в little-endian
+fn decode_hex(value: &str, name: &str) -> Result<Vec<u8>, String> {
+    match hex::decode(value) {
+        Ok(mut bytes) => {
+            bytes.reverse();
+            Ok(bytes)
+        }
+        Err(_) => Err(format!("Значение {} должно состоять из hex-символов", name)),
+    }
+}
+
+// Контрольная сумма текста файла: хэш-код Стрибог-256
+fn checksum(text: &str) -> Result<String, String> {
+    Ok(to_hex(&streebog(text.as_bytes(), 256)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_key_file_rejects_damaged_files() {
+        let master = [7u8; 32];
+        let meta = KeyFileMeta::new("user", 128, 2, &[1u8; 32]);
+
+        let text = key_file_text("Kuznechik", 16, &master, &meta, None).unwrap();
+        let text = text.expose();
+
+        let (loaded, loaded_meta) = parse_key_file(text, "Kuznechik", 16, None).unwrap();
+        assert_eq!(loaded.expose(), &master);
+        assert_eq!(loaded_meta, meta);
+
+        // Другой алгоритм
+        assert!(parse_key_file(text, "Magma", 8, None).is_err());
+
+        // Обрезанный файл
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(parse_key_file(&lines[..8].join("\n"), "Kuznechik", 16, None).is_err());
+        assert!(parse_key_file("", "Kuznechik", 16, None).is_err());
+
+        // Измененный файл
+        assert!(parse_key_file(&text.replace("OWNER = user", "OWNER = admin"), "Kuznechik", 16, None).is_err());
+
+        // Несогласованные параметры или другая версия при корректной контрольной сумме
+        let with_checksum = |text: &str| {
+            let body = &text[..text.rfind("CHECKSUM").unwrap()];
+            format!("{}CHECKSUM = {}\n", body, checksum(body).unwrap())
+        };
+
+        let forged = with_checksum(&text.replace("Z = 2", "Z = 3"));
+        assert!(parse_key_file(&forged, "Kuznechik", 16, None).unwrap_err().contains("IV"));
+
+        let forged = with_checksum(&text.replace("VERSION = 1", "VERSION = 2"));
+        assert!(parse_key_file(&forged, "Kuznechik", 16, None).unwrap_err().contains("версия"));
+
+        // Некорректные параметры не записываются
+        let bad_meta = KeyFileMeta::new("user", 129, 2, &[1u8; 32]);
+        assert!(key_file_text("Kuznechik", 16, &master, &bad_meta, None).is_err());
+    }
+
+    #[test]
+    fn test_password_protected_key_file() {
+        let master = [0x5Au8; 32];
+        let meta = KeyFileMeta::new("user", 64, 1, &[2u8; 8]);
+
+        let text = key_file_text("Magma", 8, &master, &meta, Some(("пароль", 3))).unwrap();
+        let text = text.expose();
+
+        // Мастер-ключ не записан в открытом виде
+        assert!(!text.contains(&to_hex(&master)));
+        assert!(text.contains("ITERATIONS = 3"));
+
+        let (loaded, loaded_meta) = parse_key_file(text, "Magma", 8, Some("пароль")).unwrap();
+        assert_eq!(loaded.expose(), &master);
+        assert_eq!(loaded_meta, meta);
+
+        // Неверный пароль или его отсутствие обнаруживаются
+        assert!(parse_key_file(text, "Magma", 8, Some("Пароль")).unwrap_err().contains("Неверный пароль"));
+        assert!(parse_key_file(text, "Magma", 8, None).unwrap_err().contains("защищен паролем"));
+
+        // Пароль к незащищенному файлу не игнорируется
+        let plain = key_file_text("Magma", 8, &master, &meta, None).unwrap();
+        assert!(parse_key_file(plain.expose(), "Magma", 8, Some("пароль")).unwrap_err().contains("не защищен"));
+
+        // Подмена ITERATIONS или метаданных с пересчетом CHECKSUM обнаруживается по K_MAC
+        let with_checksum = |text: &str| {
+            let body = &text[..text.rfind("CHECKSUM").unwrap()];
+            format!("{}CHECKSUM = {}\n", body, checksum(body).unwrap())
+        };
+
+        for (from, to) in [("ITERATIONS = 3", "ITERATIONS = 1"), ("OWNER = user", "OWNER = admin"), ("S = 64", "S = 32")] {
+            let forged = with_checksum(&text.replace(from, to));
+            assert!(parse_key_file(&forged, "Magma", 8, Some("пароль")).unwrap_err().contains("изменен"));
+        }
+
+        // Соль случайная, поэтому шифротекст ключа каждый раз разный
+        let other = key_file_text("Magma", 8, &master, &meta, Some(("пароль", 3))).unwrap();
+        assert_ne!(other.expose(), text);
+    }
+}
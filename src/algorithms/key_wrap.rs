@@ -0,0 +1,199 @@
+use zeroize::Zeroize;
+
+use super::{
+    block_cipher_modes::{kdf_cmac, CipherModes, CMAC},
+    kuznechik::Kuznechik,
+    secret::Secret,
+    FromMasterKey,
+};
+
+// Метка выработки K_Exp_ENC и K_Exp_MAC из одного ключа экспорта
+const KDF_LABEL: &[u8] = b"KExp15";
+
+/// Экспорт и импорт ключей KExp15 / KImp15 (Р 1323565.1.017-2018):
+/// KEYMAC = OMAC(K_Exp_MAC, IV || K), KEXP = CTR(K_Exp_ENC, IV, K || KEYMAC),
+/// где IV длины n/2 передается вместе с KEXP. Экспортируемый ключ K - мастер-ключ
+/// длиной 256 бит в little-endian, как в KuznechikKey и MagmaKey.
+#[allow(dead_code)]
+pub struct KeyWrap<C: FromMasterKey = Kuznechik> {
+    modes: CipherModes<C>,
+    cmac: CMAC<C>,
+}
+
+#[allow(dead_code)]
+impl<C: FromMasterKey> KeyWrap<C> {
+    /// Экспорт на ключах шифрования k_exp_enc и имитозащиты k_exp_mac
+    pub fn new(k_exp_enc: C, k_exp_mac: C) -> Result<Self, String> {
+        Ok(Self {
+            modes: CipherModes::new(k_exp_enc),
+            cmac: CMAC::new(k_exp_mac)?,
+        })
+    }
+
+    /// Экспорт на одном ключе экспорта kek: K_Exp_ENC и K_Exp_MAC вырабатываются из него (см. kdf_cmac)
+    pub fn from_kek(kek: C) -> Result<Self, String> {
+        let (k_exp_enc, k_exp_mac) = kdf_cmac(kek, KDF_LABEL)?;
+
+        Self::new(k_exp_enc, k_exp_mac)
+    }
+
+    /// KExp15: экспорт ключа key на IV длины n/2 байт, результат длины 32 + n байт
+    pub fn kexp15(&self, key: &[u8; 32], iv: &[u8]) -> Result<Vec<u8>, String> {
+        let n = C::BLOCK_SIZE;
+
+        let mut authenticated = mac_input(iv, key, n);
+        let key_mac = self.cmac.cmac(&authenticated, n * 8);
+        authenticated.zeroize();
+
+        // K || KEYMAC
+        let mut data = key_string(key, n);
+        data.extend(key_mac?);
+
+        let res = self.modes.ctr_crypt(&data, n * 8, iv);
+        data.zeroize();
+
+        res
+    }
+
+    /// KImp15: импорт ключа из KEXP на IV. Если KEXP изменен или ключи экспорта другие,
+    /// имитовставка не совпадает и ключ не возвращается.
+    pub fn kimp15(&self, wrapped: &[u8], iv: &[u8]) -> Result<Secret<[u8; 32]>, String> {
+        let n = C::BLOCK_SIZE;
+
+        if wrapped.len() != 32 + n {
+            return Err(format!("Длина экспортированного ключа должна быть {} байт", 32 + n));
+        }
+
+        let mut data = self.modes.ctr_crypt(wrapped, n * 8, iv)?;
+        let (key, key_mac) = data.split_at(32);
+
+        let mut key = key_string(key, n);
+        let mut res = Secret::new([0u8; 32]);
+        res.expose_mut().copy_from_slice(&key);
+        key.zeroize();
+
+        let mut authenticated = mac_input(iv, res.expose(), n);
+        let valid = self.cmac.verify(&authenticated, key_mac);
+        authenticated.zeroize();
+
+        let res = match valid {
+            Ok(true) => Ok(res),
+            Ok(false) => Err("Имитовставка экспортированного ключа не совпадает".to_string()),
+            Err(msg) => Err(msg),
+        };
+
+        data.zeroize();
+
+        res
+    }
+}
+
+// Сообщение IV || K для KEYMAC в представлении сообщений крейта: строка бит IV || K (IV - n/2 байт,
+// хранится в little-endian, как в CTR) делится на блоки по n байт с начала, байты каждого блока,
+// в том числе неполного последнего, записываются в обратном порядке
+fn mac_input(iv: &[u8], key: &[u8; 32], n: usize) -> Vec<u8> {
+    let mut bits: Vec<u8> = iv.iter().rev().chain(key.iter().rev()).copied().collect();
+    let res = bits.chunks(n).flat_map(|block| block.iter().rev()).copied().collect();
+    bits.zeroize();
+
+    res
+}
+
+// Ключ как строка бит K в представлении сообщений крейта: n-байтовые блоки строки K идут
+// от старшего к младшему, а в мастер-ключе (little-endian) - наоборот. Преобразование обратно себе.
+fn key_string(key: &[u8], n: usize) -> Vec<u8> {
+    key.chunks(n).rev().flatten().copied().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{
+        hex_to_bytes,
+        kuznechik::KuznechikKey,
+        magma::{Magma, MagmaKey},
+        random_vec,
+    };
+
+    // Строка big-endian из примера в представление сообщений крейта (блоки по n байт)
+    fn blocks(hex: &str, n: usize) -> Vec<u8> {
+        hex_to_bytes(hex).chunks(n).flat_map(|block| block.iter().rev().copied()).collect()
+    }
+
+    // Р 1323565.1.017-2018, пример KExp15 для Кузнечика
+    #[test]
+    fn test_kexp15_kuznechik() {
+        let key = KuznechikKey::from_hex("8899aabbccddeeff0011223344556677fedcba98765432100123456789abcdef").unwrap();
+        let k_exp_mac = KuznechikKey::from_hex("202122232425262728292a2b2c2d2e2f38393a3b3c3d3e3f3031323334353637").unwrap();
+        let k_exp_enc = KuznechikKey::from_hex("08090a0b0c0d0e0f0001020304050607101112131415161718191a1b1c1d1e1f").unwrap();
+        let iv = hex_to_bytes("0909090909090909");
+
+        let wrap = KeyWrap::new(Kuznechik::with_key(k_exp_enc), Kuznechik::with_key(k_exp_mac)).unwrap();
+
+        let expected = blocks(
+            concat!(
+                "a3a59b59ee25a1a131525280934baad8",
+                "a63b07d8acdc6b72e06f0d9b798b90e6",
+                "c41d26373d4baac811f432b08542e738",
+            ),
+            16,
+        );
+
+        let wrapped = wrap.kexp15(key.master_key(), &iv).unwrap();
+        assert_eq!(wrapped, expected);
+        assert_eq!(wrap.kimp15(&wrapped, &iv).unwrap().expose(), key.master_key());
+    }
+
+    // Р 1323565.1.017-2018, пример KExp15 для Магмы: IV неполный блок, MAC-сообщение IV || K
+    // не выровнено по границе блока
+    #[test]
+    fn test_kexp15_magma() {
+        let key = MagmaKey::from_hex("8899aabbccddeeff0011223344556677fedcba98765432100123456789abcdef").unwrap();
+        let k_exp_mac = MagmaKey::from_hex("08090a0b0c0d0e0f0001020304050607101112131415161718191a1b1c1d1e1f").unwrap();
+        let k_exp_enc = MagmaKey::from_hex("202122232425262728292a2b2c2d2e2f38393a3b3c3d3e3f3031323334353637").unwrap();
+        let mut iv = hex_to_bytes("67bed654");
+        iv.reverse();
+
+        let wrap = KeyWrap::new(Magma::with_key(k_exp_enc), Magma::with_key(k_exp_mac)).unwrap();
+
+        let expected = blocks(
+            concat!(
+                "cfd5a12d5b81b6e1e99c916d07900c6a",
+                "c12703fb3abded55567bf3742c899c75",
+                "5dafe7b42e3a8bd9",
+            ),
+            8,
+        );
+
+        let wrapped = wrap.kexp15(key.master_key(), &iv).unwrap();
+        assert_eq!(wrapped, expected);
+        assert_eq!(wrap.kimp15(&wrapped, &iv).unwrap().expose(), key.master_key());
+    }
+
+    #[test]
+    fn test_kimp15_rejects_modified() {
+        let wrap = KeyWrap::from_kek(Magma::with_key(MagmaKey::generate())).unwrap();
+        let key = MagmaKey::generate();
+        let iv = random_vec(4);
+
+        let wrapped = wrap.kexp15(key.master_key(), &iv).unwrap();
+        assert_eq!(wrapped.len(), 40);
+        assert_eq!(wrap.kimp15(&wrapped, &iv).unwrap().expose(), key.master_key());
+
+        for idx in 0..wrapped.len() {
+            let mut bad = wrapped.clone();
+            bad[idx] ^= 0x10;
+            assert!(wrap.kimp15(&bad, &iv).is_err(), "байт {}", idx);
+        }
+
+        let mut bad_iv = iv.clone();
+        bad_iv[0] ^= 1;
+        assert!(wrap.kimp15(&wrapped, &bad_iv).is_err());
+        assert!(wrap.kimp15(&wrapped[1..], &iv).is_err());
+        assert!(wrap.kexp15(key.master_key(), &random_vec(8)).is_err());
+
+        // Другой ключ экспорта
+        let other = KeyWrap::from_kek(Magma::with_key(MagmaKey::generate())).unwrap();
+        assert!(other.kimp15(&wrapped, &iv).is_err());
+    }
+}
@@ -0,0 +1,148 @@
+use std::sync::OnceLock;
+
+use crate::algorithms::kuznechik::{
+    consts::{KUZ_PI, KUZ_PI_INV},
+    Kuznechik, KuznechikKey,
+};
+
+/// Реализация блочного преобразования Кузнечика с постоянным временем выполнения.
+/// Нелинейное преобразование S вычисляется в bitsliced-виде через алгебраическую
+/// нормальную форму (АНФ) подстановки Pi: все 16 байт блока обрабатываются одновременно,
+/// без обращений к памяти по секретному индексу. Линейное преобразование L вычисляется
+/// как умножение на двоичную матрицу 128x128 с маскированием строк, без ветвлений.
+struct Tables {
+    pi_anf: [[u16; 256]; 8],     // Маски коэффициентов АНФ каждого выходного бита Pi
+    pi_inv_anf: [[u16; 256]; 8], // Маски коэффициентов АНФ каждого выходного бита Pi^-1
+    l_rows: [u128; 128],         // L(e_i), где e_i - вектор с единственным i-м битом
+    l_inv_rows: [u128; 128],     // L^-1(e_i)
+}
+
+static TABLES: OnceLock<Box<Tables>> = OnceLock::new();
+
+// Таблицы зависят только от констант стандарта и строятся один раз
+fn tables() -> &'static Tables {
+    TABLES.get_or_init(|| {
+        let mut tables = Box::new(Tables {
+            pi_anf: anf(&KUZ_PI),
+            pi_inv_anf: anf(&KUZ_PI_INV),
+            l_rows: [0; 128],
+            l_inv_rows: [0; 128],
+        });
+
+        for bit in 0..128 {
+            let e = (1u128 << bit).to_le_bytes();
+
+            tables.l_rows[bit] = u128::from_le_bytes(Kuznechik::l(&e));
+            tables.l_inv_rows[bit] = u128::from_le_bytes(Kuznechik::l_inv(&e));
+        }
+
+        tables
+    })
+}
+
+// АНФ каждого выходного бита подстановки (преобразование Мёбиуса).
+// Коэффициент при мономе x_S хранится маской 0xFFFF/0x0000.
+fn anf(sbox: &[u8; 256]) -> [[u16; 256]; 8] {
+    let mut res = [[0u16; 256]; 8];
+
+    for (bit, coefs) in res.iter_mut().enumerate() {
+        let mut f = [0u8; 256];
+        for (x, value) in sbox.iter().enumerate() {
+            f[x] = (value >> bit) & 1;
+        }
+
+        for i in 0..8 {
+            for x in 0..256 {
+                if x & (1 << i) != 0 {
+                    f[x] ^= f[x ^ (1 << i)];
+                }
+            }
+        }
+
+        for (coef, value) in coefs.iter_mut().zip(f.iter()) {
+            *coef = 0u16.wrapping_sub(*value as u16);
+        }
+    }
+
+    res
+}
+
+// Подстановка всех 16 байт блока через АНФ в bitsliced-представлении
+fn sbox(anf: &[[u16; 256]; 8], a: &[u8; 16]) -> [u8; 16] {
+    // x[i] - i-е биты всех 16 байт блока
+    let mut x = [0u16; 8];
+    for (pos, byte) in a.iter().enumerate() {
+        for (i, slice) in x.iter_mut().enumerate() {
+            *slice |= (((byte >> i) & 1) as u16) << pos;
+        }
+    }
+
+    // Значения всех мономов x_S = x_i1 & x_i2 & ... для S из 0..256
+    let mut monomials = [0u16; 256];
+    monomials[0] = 0xFFFF;
+    for set in 1..256usize {
+        monomials[set] = monomials[set & (set - 1)] & x[set.trailing_zeros() as usize];
+    }
+
+    let mut y = [0u16; 8];
+    for (slice, coefs) in y.iter_mut().zip(anf.iter()) {
+        for (monomial, coef) in monomials.iter().zip(coefs.iter()) {
+            *slice ^= monomial & coef;
+        }
+    }
+
+    let mut res = [0u8; 16];
+    for (pos, byte) in res.iter_mut().enumerate() {
+        for (i, slice) in y.iter().enumerate() {
+            *byte |= (((slice >> pos) & 1) as u8) << i;
+        }
+    }
+
+    res
+}
+
+// Умножение вектора на двоичную матрицу линейного преобразования
+fn linear(rows: &[u128; 128], a: &[u8; 16]) -> [u8; 16] {
+    let a = u128::from_le_bytes(*a);
+    let mut res: u128 = 0;
+
+    for (bit, row) in rows.iter().enumerate() {
+        res ^= row & 0u128.wrapping_sub((a >> bit) & 1);
+    }
+
+    res.to_le_bytes()
+}
+
+/// Шифрование одного блока 128 бит на итерационных ключах round_keys (10 ключей).
+pub fn encrypt_block(round_keys: &[[u8; 16]], block: &[u8; 16]) -> [u8; 16] {
+    let mut a = *block;
+
+    for key in &round_keys[..9] {
+        a = lsx(key, &a);
+    }
+
+    Kuznechik::x(&round_keys[9], &a)
+}
+
+/// Преобразование LSX[k](a), используемое при развертке ключа
+pub fn lsx(k: &[u8; 16], a: &[u8; 16]) -> [u8; 16] {
+    let tables = tables();
+
+    let a = sbox(&tables.pi_anf, &Kuznechik::x(k, a));
+    linear(&tables.l_rows, &a)
+}
+
+/// Расшифрование одного блока 128 бит на ключе keys.
+pub fn decrypt_block(keys: &KuznechikKey, block: &[u8; 16]) -> [u8; 16] {
+    let tables = tables();
+    let round_keys = keys.round_keys();
+    let mut a = *block;
+
+    for key in round_keys[1..].iter().rev() {
+        a = Kuznechik::x(key, &a);
+        a = linear(&tables.l_inv_rows, &a);
+        a = sbox(&tables.pi_inv_anf, &a);
+    }
+
+    Kuznechik::x(&round_keys[0], &a)
+}
@@ -0,0 +1,189 @@
+use std::path::{Path, PathBuf};
+
+use zeroize::Zeroize;
+
+use crate::algorithms::{
+    key_file::{
+        master_key_from_hex, master_key_from_slice, random_master_key, read_key_file, write_key_file, KeyFileMeta,
+    },
+    kuznechik::Kuznechik,
+    secret::Secret,
+    BlockCipher,
+};
+
+/// Ключ Кузнечика: мастер-ключ K длиной 256 бит и 10 итерационных ключей,
+/// которые всегда вычисляются из K, вместе с их образами L^-1(K_i) для расшифрования.
+/// Байты хранятся в little-endian, как и во всем крейте.
+/// Ключ затирается при удалении, не выводится через Debug и копируется только явно.
+#[derive(Debug)]
+pub struct KuznechikKey {
+    master: Secret<[u8; 32]>,
+    round_keys: Secret<[[u8; 16]; 10]>,
+    // В куче, чтобы не увеличивать размер ключа (он хранится в перечислениях GUI)
+    l_inv_round_keys: Secret<Vec<[u8; 16]>>,
+}
+
+#[allow(dead_code)]
+impl KuznechikKey {
+    /// Создание ключа из мастер-ключа K с вычислением итерационных ключей
+    pub fn new(key: &[u8; 32]) -> Self {
+        let round_keys = Secret::new(Self::expand(key));
+        let l_inv_round_keys = Secret::new(round_keys.expose().iter().map(Kuznechik::l_inv).collect());
+
+        Self {
+            master: Secret::new(*key),
+            round_keys,
+            l_inv_round_keys,
+        }
+    }
+
+    /// Генерация случайного мастер-ключа
+    pub fn generate() -> Self {
+        Self::new(random_master_key().expose())
+    }
+
+    /// Создание ключа из строки из 64 hex-символов в том виде, в котором ключ
+    /// отображается в GUI и записывается в файл (big-endian).
+    pub fn from_hex(hex: &str) -> Result<Self, String> {
+        Ok(Self::new(master_key_from_hex(hex)?.expose()))
+    }
+
+    /// Загрузка ключа из файла ключа (см. key_file): проверяются версия, алгоритм,
+    /// контрольная сумма, параметры и пароль (для защищенных файлов),
+    /// итерационные ключи вычисляются заново из K.
+    pub fn from_file(path: &Path, password: Option<&str>) -> Result<(Self, KeyFileMeta), String> {
+        let (master, meta) = read_key_file(path, "Kuznechik", Kuznechik::BLOCK_SIZE, password)?;
+
+        Ok((Self::new(master.expose()), meta))
+    }
+
+    /// Запись мастер-ключа и метаданных в файл ключа (итерационные ключи не записываются).
+    /// Если передан пароль, мастер-ключ записывается зашифрованным.
+    pub fn save_to_file(&self, path: &Path, meta: &KeyFileMeta, password: Option<&str>) -> Result<PathBuf, String> {
+        write_key_file(path, "Kuznechik", Kuznechik::BLOCK_SIZE, self.master_key(), meta, password)
+    }
+
+    /// Мастер-ключ K
+    pub fn master_key(&self) -> &[u8; 32] {
+        self.master.expose()
+    }
+
+    /// Итерационные ключи K1..K10
+    pub fn round_keys(&self) -> &[[u8; 16]; 10] {
+        self.round_keys.expose()
+    }
+
+    /// Итерационные ключи после преобразования L^-1: L^-1(K1)..L^-1(K10)
+    pub fn l_inv_round_keys(&self) -> &[[u8; 16]] {
+        self.l_inv_round_keys.expose()
+    }
+
+    /// Явное копирование ключа
+    pub fn clone_secret(&self) -> Self {
+        Self {
+            master: self.master.clone_secret(),
+            round_keys: self.round_keys.clone_secret(),
+            l_inv_round_keys: self.l_inv_round_keys.clone_secret(),
+        }
+    }
+
+    // Развертка ключа по п. 4.3 ГОСТ Р 34.12-2018
+    fn expand(k: &[u8; 32]) -> [[u8; 16]; 10] {
+        let c_vec = Kuznechik::iterational_constants();
+        let mut round_keys: [[u8; 16]; 10] = [[0; 16]; 10];
+
+        let mut k1: [u8; 16] = [0; 16];
+        k1.copy_from_slice(&k[16..]);
+        round_keys[0] = k1;
+
+        let mut k2: [u8; 16] = [0; 16];
+        k2.copy_from_slice(&k[..16]);
+        round_keys[1] = k2;
+
+        for i in 1..5 {
+            // F [С_8(i-1)+8]...F[С_8(i-1)+1](K_2i-1, K_2i)
+            for iter in 0..8 {
+                (k1, k2) = Kuznechik::fk(&c_vec[8 * (i - 1) + iter], &k1, &k2);
+            }
+
+            round_keys[2 * i] = k1;
+            round_keys[2 * i + 1] = k2;
+        }
+
+        // Затирание промежуточных копий ключей на стеке
+        k1.zeroize();
+        k2.zeroize();
+
+        round_keys
+    }
+}
+
+impl TryFrom<&[u8]> for KuznechikKey {
+    type Error = String;
+
+    /// Создание ключа из среза байтов с проверкой длины (32 байта)
+    fn try_from(key: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::new(master_key_from_slice(key)?.expose()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{hex_to_bytes, self_test::KUZNECHIK_VECTORS};
+
+    const K_GOST: &str = KUZNECHIK_VECTORS.key;
+
+    #[test]
+    fn test_key_constructors() {
+        let mut k = hex_to_bytes(K_GOST);
+        k.reverse();
+
+        let from_slice = KuznechikKey::try_from(&k[..]).unwrap();
+        let from_hex = KuznechikKey::from_hex(K_GOST).unwrap();
+
+        assert_eq!(from_slice.master_key(), from_hex.master_key());
+        assert_eq!(from_slice.round_keys(), from_hex.round_keys());
+
+        // K10 из ГОСТ Р 34.12-2018
+        let mut k10 = hex_to_bytes("72e9dd7416bcf45b755dbaa88e4a4043");
+        k10.reverse();
+        assert_eq!(from_hex.round_keys()[9].to_vec(), k10);
+
+        // Некорректная длина или символы
+        assert!(KuznechikKey::try_from(&k[..31]).is_err());
+        assert!(KuznechikKey::from_hex(&K_GOST[2..]).is_err());
+        assert!(KuznechikKey::from_hex(&K_GOST.replace('a', "x")).is_err());
+    }
+
+    #[test]
+    fn test_key_file() {
+        let path = std::env::temp_dir().join(format!("kuznechik_key_test_{}", std::process::id()));
+
+        let key = KuznechikKey::generate();
+        let meta = KeyFileMeta::new("user", 64, 1, &[0xAB; 16]);
+        key.save_to_file(&path, &meta, None).unwrap();
+
+        // В файле только мастер-ключ, итерационные ключи вычисляются заново
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert!(!text.contains(&crate::algorithms::to_hex(&key.round_keys()[2])));
+
+        let (loaded, loaded_meta) = KuznechikKey::from_file(&path, None).unwrap();
+        assert_eq!(key.master_key(), loaded.master_key());
+        assert_eq!(key.round_keys(), loaded.round_keys());
+        assert_eq!(loaded_meta, meta);
+
+        // Ключ Кузнечика не загружается как ключ Магмы
+        assert!(crate::algorithms::magma::MagmaKey::from_file(&path, None).is_err());
+
+        // Измененный мастер-ключ
+        let k = crate::algorithms::to_hex(key.master_key());
+        let mut wrong_k = *key.master_key();
+        wrong_k[0] ^= 1;
+        std::fs::write(&path, text.replace(&k, &crate::algorithms::to_hex(&wrong_k))).unwrap();
+
+        assert!(KuznechikKey::from_file(&path, None).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
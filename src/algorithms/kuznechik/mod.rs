@@ -1,19 +1,30 @@
-use std::{
-    fs::File,
-    io::{BufRead, BufReader, Write},
-    path::PathBuf,
-    vec,
-};
+use std::vec;
 
 use crate::algorithms::{
-    hex_to_bytes, kuznechik::consts::{KUZ_PI, KUZ_PI_INV, L_VEC}, random_vec, to_hex
+    kuznechik::consts::{KUZ_PI, KUZ_PI_INV, L_VEC},
+    BlockCipher, FromMasterKey,
 };
 
 pub mod consts;
+mod key;
+
+pub use key::KuznechikKey;
+
+// Реализация блочного преобразования выбирается cargo-фичей constant-time.
+// В тестах собираются обе реализации, чтобы сверять их между собой.
+#[cfg(any(feature = "constant-time", test))]
+mod ct;
+#[cfg(any(not(feature = "constant-time"), test))]
+mod tables;
 
-#[derive(Debug, Clone)]
+#[cfg(feature = "constant-time")]
+use ct as backend;
+#[cfg(not(feature = "constant-time"))]
+use tables as backend;
+
+#[derive(Debug)]
 pub struct Kuznechik {
-    pub keys: (Vec<u8>, Vec<[u8; 16]>),
+    pub keys: KuznechikKey,
 }
 
 #[allow(dead_code)]
@@ -21,10 +32,21 @@ impl Kuznechik {
     // Создает структуру с инициализированными изначально ключами
     pub fn new() -> Self {
         Self {
-            keys: Self::key_generate(),
+            keys: KuznechikKey::generate(),
         }
     }
 
+    /// Создает структуру с переданным ключом
+    pub fn with_key(keys: KuznechikKey) -> Self {
+        Self { keys }
+    }
+
+    /// Явное копирование ключей. Clone не реализован, чтобы ключи
+    /// не копировались незаметно.
+    pub fn clone_secret(&self) -> Self {
+        Self::with_key(self.keys.clone_secret())
+    }
+
     /// Конечное поле GF(2){x}/p(x), где р(х) = х^8 + х^7 + х^6 + х + 1 принадлежит GF(2){x};
     /// элементы поля F представляются целыми числами, причем элементу z0 + z1•t + ... + z7•t, принадлежащему F, соответствует число z0+ 2•z1 + ...+2•z7,
     /// где zi принадлежит {0, 1}, i = 0, 1,..., 7, и t обозначает класс вычетов по модулю р(х), содержащий х;
@@ -35,20 +57,13 @@ impl Kuznechik {
         let mut b = *elem2;
         let mut res: u8 = 0;
 
-        let mut carry; // Проверка, что степень числа < 8
-
+        // Ветвления заменены масками, чтобы время умножения не зависело от сомножителей
         for _ in 0..8 {
             // Младший бит 1 - значит умножаем
-            if b & 1 != 0 {
-                res ^= a;
-            }
-
-            carry = a & 0x80;
-            a <<= 1; // Сдвиг влево множимого
+            res ^= a & 0u8.wrapping_sub(b & 1);
 
-            if carry != 0 {
-                a ^= px; // Xor множимого, так как степень > 7
-            }
+            // Сдвиг влево множимого и Xor с px, если степень > 7
+            a = (a << 1) ^ (px & 0u8.wrapping_sub(a >> 7));
 
             b >>= 1; // Сдвиг множителя право
         }
@@ -181,7 +196,7 @@ impl Kuznechik {
     fn fk(k: &[u8; 16], a1: &[u8; 16], a0: &[u8; 16]) -> ([u8; 16], [u8; 16]) {
         let mut res: [u8; 16];
 
-        res = Self::lsx(k, a1);
+        res = backend::lsx(k, a1);
         res = Self::x(&res, a0); // Можно использовать X, т.к. оно реализует суммирование mod2
 
         (res, a1.clone())
@@ -202,156 +217,8 @@ impl Kuznechik {
         c_vec
     }
 
-    /// Создание ключа длиной 256 бит, а также 10 итерационных ключей
-    /// Результат: (Ключ_K, вектор_итерационных_ключей)
-    /// Добавить генерацию случайного ключа K
-    pub fn key_generate() -> (Vec<u8>, Vec<[u8; 16]>) {
-        let c_vec = Self::iterational_constants();
-
-        // Ключ из ГОСТ Р. Возможно после заменить на случайную генерацию
-        let k = random_vec(32);
-        //hex_to_bytes("8899aabbccddeeff0011223344556677fedcba98765432100123456789abcdef");
-        //k.reverse();
-
-        let mut k_vec: Vec<[u8; 16]> = Vec::with_capacity(10); // Все итерационные ключи
-
-        let mut k1: [u8; 16] = [0; 16];
-        k1.copy_from_slice(&k[16..]);
-        k_vec.push(k1);
-
-        let mut k2: [u8; 16] = [0; 16];
-        k2.copy_from_slice(&k[..16]);
-        k_vec.push(k2);
-
-        for i in 1..5 as usize {
-            // F [С_8(i-1)+8]...F[С_8(i-1)+1](K_2i-1, K_2i)
-            for iter in 0..8 as usize {
-                (k1, k2) = Self::fk(&c_vec[8 * (i - 1) + iter], &k1, &k2);
-            }
-
-            // Пушит копию (до 32 байт)
-            k_vec.push(k1);
-            k_vec.push(k2);
-        }
-
-        let pair_keys = (k, k_vec);
-
-        //let path: PathBuf = PathBuf::from("./Kuznechik_keys");
-        //let _ = Self::save_keys_into_file(&pair_keys, &path);
-
-        pair_keys
-    }
-
-    // Создание остальных ключей на основе переданного
-    pub fn key_generate_with_precopmuted_key(key: &[u8]) ->(Vec<u8>, Vec<[u8; 16]>) {
-        let c_vec = Self::iterational_constants();
-
-        // Ключ, на основе которого будут считать остальные ключи
-        let mut k: [u8; 32] = [0; 32];
-
-        if key.len() != 32 {
-            panic!("Error key len. Length must be 32, but pass {}", key.len());
-        }
-
-        k.copy_from_slice(key);
-
-        let mut k_vec: Vec<[u8; 16]> = Vec::with_capacity(10); // Все итерационные ключи
-
-        let mut k1: [u8; 16] = [0; 16];
-        k1.copy_from_slice(&k[16..]);
-        k_vec.push(k1);
-
-        let mut k2: [u8; 16] = [0; 16];
-        k2.copy_from_slice(&k[..16]);
-        k_vec.push(k2);
-
-        for i in 1..5 as usize {
-            // F [С_8(i-1)+8]...F[С_8(i-1)+1](K_2i-1, K_2i)
-            for iter in 0..8 as usize {
-                (k1, k2) = Self::fk(&c_vec[8 * (i - 1) + iter], &k1, &k2);
-            }
-
-            // Пушит копию (до 32 байт)
-            k_vec.push(k1);
-            k_vec.push(k2);
-        }
-
-        (k.to_vec(), k_vec)
-    }
-
-    pub fn save_keys_into_file(keys: &(Vec<u8>, Vec<[u8; 16]>), path: &PathBuf) ->  Result<PathBuf, String> {
-        let mut file = match File::create(path) 
-        {
-            Ok(file) => file,
-            Err(_) => {return Err("Ошибка создания файла".to_string());}
-        };
-
-        match writeln!(file, "K = {}", to_hex(&keys.0))
-        {
-            Ok(_) => {},
-            Err(_) => {return Err("Ошибка записи ключей".to_string());}
-        };
-
-        // Запись всех итерационных ключей в файл
-        for idx in 0..keys.1.len() {
-            match writeln!(file, "K{} = {}", idx + 1, to_hex(&keys.1[idx]))
-            {
-                Ok(_) => {},
-                Err(_) => {return Err("Ошибка записи ключей".to_string());}
-            };
-        }
-
-        Ok(path.clone())
-    }
-
-    pub fn get_keys_from_file(path: &PathBuf) -> Result<(Vec<u8>, Vec<[u8; 16]>), String> {
-        let file = match File::open(path)
-        {
-            Ok(file) => file,
-            Err(_) => {return Err("Ошибка открытия файла с ключами".to_string());}
-        };
-
-        let reader = BufReader::new(file);
-
-        let mut main_key: Vec<u8> = Vec::new();
-        let mut round_keys: Vec<[u8; 16]> = Vec::new();
-
-        for line in reader.lines() {
-            let line = match line 
-            {
-                Ok(line) => line,
-                Err(_) => {return Err(" Некорректный файл с ключами".to_string());}
-            };
-
-            let parts: Vec<&str> = line.split('=').map(|s| s.trim()).collect();
-
-            if parts.len() != 2
-            {
-                return Err(" Некорректный файл с ключами".to_string());
-            }
-
-            let name = parts[0];
-            let value = parts[1];
-
-            let mut bytes = hex_to_bytes(value);
-            bytes.reverse();
-
-            if name == "K" {
-                main_key = bytes;
-            } else {
-                let mut arr = [0u8; 16];
-                arr.copy_from_slice(&bytes);
-                round_keys.push(arr);
-            }
-        }
-
-        Ok((main_key, round_keys))
-    }
-
     /// Шифрование Message блоками длины 128 бит
     pub fn encrypt(&self, message: &[u8]) -> Result<[u8; 16], String> {
-        // let mut file = File::create(PathBuf::from("./encryption_Kuznechik")).unwrap();
-
         if message.len() * 8 != 128 {
             return Err("Message must be len = 128 bits".to_string());
         }
@@ -360,15 +227,7 @@ impl Kuznechik {
         let mut a: [u8; 16] = [0; 16];
         a.copy_from_slice(message);
 
-        for iter in 0..9 {
-            a = Self::lsx(&self.keys.1[iter], &a);
-        }
-
-        a = Self::x(&self.keys.1[9], &a);
-
-        // write!(file, "{}", to_hex(&a)).unwrap();
-
-        return Ok(a);
+        Ok(backend::encrypt_block(self.keys.round_keys(), &a))
     }
 
     /// Расшифрование M по блокам длины 128
@@ -381,13 +240,27 @@ impl Kuznechik {
         let mut a: [u8; 16] = [0; 16];
         a.copy_from_slice(message);
 
-        for iter in 0..9 {
-            a = Self::s_inv_l_inv_x(&self.keys.1[9 - iter], &a);
-        }
+        Ok(backend::decrypt_block(&self.keys, &a))
+    }
+}
 
-        a = Self::x(&self.keys.1[0], &a);
+impl BlockCipher for Kuznechik {
+    const BLOCK_SIZE: usize = 16;
 
-        Ok(a)
+    fn encrypt_block(&self, block: &mut [u8]) {
+        let a: &mut [u8; 16] = block.try_into().expect("Длина блока Кузнечика 16 байт");
+        *a = backend::encrypt_block(self.keys.round_keys(), a);
+    }
+
+    fn decrypt_block(&self, block: &mut [u8]) {
+        let a: &mut [u8; 16] = block.try_into().expect("Длина блока Кузнечика 16 байт");
+        *a = backend::decrypt_block(&self.keys, a);
+    }
+}
+
+impl FromMasterKey for Kuznechik {
+    fn from_master_key(key: &[u8; 32]) -> Self {
+        Self::with_key(KuznechikKey::new(key))
     }
 }
 
@@ -395,7 +268,10 @@ impl Kuznechik {
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
     use super::*;
-    use crate::algorithms::hex_to_bytes;
+    use crate::algorithms::{
+        hex_to_bytes, random_vec,
+        self_test::{le_blocks, KUZNECHIK_VECTORS},
+    };
 
     #[test]
     fn test_r_transform() {
@@ -659,9 +535,6 @@ mod tests {
     #[test]
     fn test_encryption_kuznechik() {
         // Precomputed keys
-        let mut k =
-            hex_to_bytes("8899AABBCCDDEEFF0011223344556677FEDCBA98765432100123456789ABCDEF");
-        k.reverse();
         let mut k1 = hex_to_bytes("8899AABBCCDDEEFF0011223344556677");
         k1.reverse();
         let mut k2 = hex_to_bytes("FEDCBA98765432100123456789ABCDEF");
@@ -683,42 +556,25 @@ mod tests {
         let mut k10 = hex_to_bytes("72E9DD7416BCF45B755DBAA88E4A4043");
         k10.reverse();
 
-        let keys = Kuznechik {
-            keys: (
-                k,
-                vec![
-                    k1.try_into().unwrap(),
-                    k2.try_into().unwrap(),
-                    k3.try_into().unwrap(),
-                    k4.try_into().unwrap(),
-                    k5.try_into().unwrap(),
-                    k6.try_into().unwrap(),
-                    k7.try_into().unwrap(),
-                    k8.try_into().unwrap(),
-                    k9.try_into().unwrap(),
-                    k10.try_into().unwrap(),
-                ],
-            ),
-        };
+        let keys = Kuznechik::with_key(KuznechikKey::from_hex(KUZNECHIK_VECTORS.key).unwrap());
+
+        // Итерационные ключи вычисляются из K и должны совпадать с ключами из ГОСТ Р
+        let round_keys = [k1, k2, k3, k4, k5, k6, k7, k8, k9, k10];
+        for (key, correct) in keys.keys.round_keys().iter().zip(round_keys.iter()) {
+            assert_eq!(key.to_vec(), *correct);
+        }
 
-        let mut message = hex_to_bytes("1122334455667700ffeeddccbbaa9988");
-        message.reverse();
+        let (message, result) = KUZNECHIK_VECTORS.block;
 
-        let mut encrypted_message = keys.encrypt(&message).unwrap();
-        encrypted_message.reverse();
+        let encrypted_message = keys.encrypt(&le_blocks(&[message])).unwrap();
 
         // Правильный результат
-        let result = hex_to_bytes("7f679d90bebc24305a468d42b9d4edcd");
-
-        assert_eq!(encrypted_message.to_vec(), result);
+        assert_eq!(encrypted_message.to_vec(), le_blocks(&[result]));
     }
 
     #[test]
     fn test_decryption_kuznechik() {
         // Precomputed keys
-        let mut k =
-            hex_to_bytes("8899AABBCCDDEEFF0011223344556677FEDCBA98765432100123456789ABCDEF");
-        k.reverse();
         let mut k1 = hex_to_bytes("8899AABBCCDDEEFF0011223344556677");
         k1.reverse();
         let mut k2 = hex_to_bytes("FEDCBA98765432100123456789ABCDEF");
@@ -740,33 +596,113 @@ mod tests {
         let mut k10 = hex_to_bytes("72E9DD7416BCF45B755DBAA88E4A4043");
         k10.reverse();
 
-        let keys = Kuznechik {
-            keys: (
-                k,
-                vec![
-                    k1.try_into().unwrap(),
-                    k2.try_into().unwrap(),
-                    k3.try_into().unwrap(),
-                    k4.try_into().unwrap(),
-                    k5.try_into().unwrap(),
-                    k6.try_into().unwrap(),
-                    k7.try_into().unwrap(),
-                    k8.try_into().unwrap(),
-                    k9.try_into().unwrap(),
-                    k10.try_into().unwrap(),
-                ],
-            ),
-        };
+        let keys = Kuznechik::with_key(KuznechikKey::from_hex(KUZNECHIK_VECTORS.key).unwrap());
 
-        let mut message = hex_to_bytes("7f679d90bebc24305a468d42b9d4edcd");
-        message.reverse();
+        // Итерационные ключи вычисляются из K и должны совпадать с ключами из ГОСТ Р
+        let round_keys = [k1, k2, k3, k4, k5, k6, k7, k8, k9, k10];
+        for (key, correct) in keys.keys.round_keys().iter().zip(round_keys.iter()) {
+            assert_eq!(key.to_vec(), *correct);
+        }
+
+        let (result, message) = KUZNECHIK_VECTORS.block;
 
-        let mut decrypted_message = keys.decrypt(&message).unwrap();
-        decrypted_message.reverse();
+        let decrypted_message = keys.decrypt(&le_blocks(&[message])).unwrap();
 
         // Правильный результат
-        let result = hex_to_bytes("1122334455667700ffeeddccbbaa9988");
+        assert_eq!(decrypted_message.to_vec(), le_blocks(&[result]));
+    }
+
+    // Побайтовая реализация раундов по формулам ГОСТ Р 34.12-2018
+    fn reference_encrypt(round_keys: &[[u8; 16]], block: &[u8; 16]) -> [u8; 16] {
+        let mut a = *block;
+
+        for key in &round_keys[..9] {
+            a = Kuznechik::lsx(key, &a);
+        }
+
+        Kuznechik::x(&round_keys[9], &a)
+    }
+
+    fn reference_decrypt(round_keys: &[[u8; 16]], block: &[u8; 16]) -> [u8; 16] {
+        let mut a = *block;
+
+        for key in round_keys[1..].iter().rev() {
+            a = Kuznechik::s_inv_l_inv_x(key, &a);
+        }
+
+        Kuznechik::x(&round_keys[0], &a)
+    }
+
+    #[test]
+    fn test_tables_match_reference() {
+        for _ in 0..64 {
+            let keys = Kuznechik::new();
+            let block: [u8; 16] = random_vec(16).try_into().unwrap();
+
+            let encrypted = keys.encrypt(&block).unwrap();
+            assert_eq!(encrypted, reference_encrypt(keys.keys.round_keys(), &block));
+
+            let decrypted = keys.decrypt(&block).unwrap();
+            assert_eq!(decrypted, reference_decrypt(keys.keys.round_keys(), &block));
+
+            assert_eq!(keys.decrypt(&encrypted).unwrap(), block);
+        }
+    }
+
+    #[test]
+    fn test_constant_time_backend_matches_tables() {
+        for _ in 0..64 {
+            let keys = Kuznechik::new();
+            let block: [u8; 16] = random_vec(16).try_into().unwrap();
+            let k: [u8; 16] = random_vec(16).try_into().unwrap();
+
+            assert_eq!(ct::encrypt_block(keys.keys.round_keys(), &block), tables::encrypt_block(keys.keys.round_keys(), &block));
+            assert_eq!(ct::decrypt_block(&keys.keys, &block), tables::decrypt_block(&keys.keys, &block));
+            assert_eq!(ct::lsx(&k, &block), tables::lsx(&k, &block));
+        }
+    }
+
+    // Замер: cargo test --release test_backend_timings -- --ignored --nocapture
+    #[test]
+    #[ignore = "benchmark"]
+    fn test_backend_timings() {
+        use std::{hint::black_box, time::Instant};
+
+        const BLOCKS: usize = 100_000;
+
+        let keys = Kuznechik::new();
+        let round_keys = keys.keys.round_keys();
+
+        // Время шифрования и расшифрования BLOCKS блоков, каждый следующий блок - результат предыдущего
+        let measure = |encrypt: &dyn Fn(&[u8; 16]) -> [u8; 16], decrypt: &dyn Fn(&[u8; 16]) -> [u8; 16]| {
+            let mut block = [0x5Au8; 16];
+
+            let start = Instant::now();
+            for _ in 0..BLOCKS {
+                block = black_box(encrypt(&block));
+            }
+            let encrypt_time = start.elapsed();
+
+            let start = Instant::now();
+            for _ in 0..BLOCKS {
+                block = black_box(decrypt(&block));
+            }
+            let decrypt_time = start.elapsed();
+
+            // Расшифрование возвращает исходный блок
+            assert_eq!(block, [0x5Au8; 16]);
+
+            (encrypt_time, decrypt_time)
+        };
+
+        let reference = measure(&|block| reference_encrypt(round_keys, block), &|block| reference_decrypt(round_keys, block));
+        let tables = measure(&|block| tables::encrypt_block(round_keys, block), &|block| tables::decrypt_block(&keys.keys, block));
+        let ct = measure(&|block| ct::encrypt_block(round_keys, block), &|block| ct::decrypt_block(&keys.keys, block));
+
+        for (name, (encrypt_time, decrypt_time)) in [("reference", reference), ("tables", tables), ("constant-time", ct)] {
+            println!("{name}: {BLOCKS} блоков, шифрование {encrypt_time:?}, расшифрование {decrypt_time:?}");
+        }
 
-        assert_eq!(decrypted_message.to_vec(), result);
+        assert!(tables.0 < reference.0 && tables.1 < reference.1);
     }
 }
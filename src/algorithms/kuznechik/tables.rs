@@ -0,0 +1,105 @@
+use std::sync::OnceLock;
+
+use crate::algorithms::kuznechik::{
+    consts::{KUZ_PI, KUZ_PI_INV},
+    Kuznechik, KuznechikKey,
+};
+
+/// Предвычисленные таблицы преобразований раунда Кузнечика.
+/// Каждая строка таблицы хранит результат линейного преобразования для одного
+/// байта блока, поэтому преобразование всего блока сводится к 16 выборкам и XOR.
+/// Блоки хранятся как u128 в little-endian (байт 0 - младший).
+struct Tables {
+    ls: [[u128; 256]; 16],          // L(S(a)) для a = 0..0||a_j||0..0
+    l_inv: [[u128; 256]; 16],       // L^-1(a) для a = 0..0||a_j||0..0
+    l_inv_s_inv: [[u128; 256]; 16], // L^-1(S^-1(a)) для a = 0..0||a_j||0..0
+}
+
+static TABLES: OnceLock<Box<Tables>> = OnceLock::new();
+
+// Таблицы строятся один раз при первом шифровании
+fn tables() -> &'static Tables {
+    TABLES.get_or_init(|| {
+        let mut tables = Box::new(Tables {
+            ls: [[0; 256]; 16],
+            l_inv: [[0; 256]; 16],
+            l_inv_s_inv: [[0; 256]; 16],
+        });
+
+        for pos in 0..16 {
+            for byte in 0..256 {
+                let mut a: [u8; 16] = [0; 16];
+
+                a[pos] = KUZ_PI[byte];
+                tables.ls[pos][byte] = u128::from_le_bytes(Kuznechik::l(&a));
+
+                a[pos] = byte as u8;
+                tables.l_inv[pos][byte] = u128::from_le_bytes(Kuznechik::l_inv(&a));
+
+                a[pos] = KUZ_PI_INV[byte];
+                tables.l_inv_s_inv[pos][byte] = u128::from_le_bytes(Kuznechik::l_inv(&a));
+            }
+        }
+
+        tables
+    })
+}
+
+// Применение табличного преобразования ко всем 16 байтам блока
+fn apply(table: &[[u128; 256]; 16], a: u128) -> u128 {
+    let mut res: u128 = 0;
+
+    for (pos, row) in table.iter().enumerate() {
+        res ^= row[(a >> (8 * pos)) as u8 as usize];
+    }
+
+    res
+}
+
+/// Шифрование одного блока 128 бит на итерационных ключах round_keys (10 ключей).
+/// Эквивалентно последовательности X[K10]LSX[K9]...LSX[K1] из ГОСТ Р 34.12-2018.
+pub fn encrypt_block(round_keys: &[[u8; 16]], block: &[u8; 16]) -> [u8; 16] {
+    let tables = tables();
+    let mut a = u128::from_le_bytes(*block);
+
+    for key in &round_keys[..9] {
+        a = apply(&tables.ls, a ^ u128::from_le_bytes(*key));
+    }
+
+    a ^= u128::from_le_bytes(round_keys[9]);
+
+    a.to_le_bytes()
+}
+
+/// Преобразование LSX[k](a), используемое при развертке ключа
+pub fn lsx(k: &[u8; 16], a: &[u8; 16]) -> [u8; 16] {
+    let a = u128::from_le_bytes(*a) ^ u128::from_le_bytes(*k);
+
+    apply(&tables().ls, a).to_le_bytes()
+}
+
+/// Расшифрование одного блока 128 бит на ключе keys.
+/// Преобразование S^-1 L^-1 X[K] переписывается как S^-1(L^-1(a) xor L^-1(K)), что позволяет
+/// объединить S^-1 предыдущего раунда и L^-1 следующего в одну таблицу.
+/// Ключи L^-1(K_i) вычисляются один раз вместе с разверткой ключа.
+pub fn decrypt_block(keys: &KuznechikKey, block: &[u8; 16]) -> [u8; 16] {
+    let tables = tables();
+    let l_inv_keys = keys.l_inv_round_keys();
+    let a = u128::from_le_bytes(*block);
+
+    // b_1 = L^-1(a xor K10)
+    let mut b = apply(&tables.l_inv, a) ^ u128::from_le_bytes(l_inv_keys[9]);
+
+    // b_i = L^-1(S^-1(b_(i-1))) xor L^-1(K_(11-i))
+    for key in l_inv_keys[1..9].iter().rev() {
+        b = apply(&tables.l_inv_s_inv, b) ^ u128::from_le_bytes(*key);
+    }
+
+    // S^-1(b_9) xor K1
+    let mut res = b.to_le_bytes();
+    for (byte, key) in res.iter_mut().zip(keys.round_keys()[0].iter()) {
+        *byte = KUZ_PI_INV[*byte as usize] ^ key;
+    }
+
+    res
+}
@@ -0,0 +1,144 @@
+use std::path::{Path, PathBuf};
+
+use zeroize::Zeroize;
+
+use crate::algorithms::{
+    key_file::{
+        master_key_from_hex, master_key_from_slice, random_master_key, read_key_file, write_key_file, KeyFileMeta,
+    },
+    magma::Magma,
+    secret::Secret,
+    BlockCipher,
+};
+
+/// Ключ Магмы: мастер-ключ K длиной 256 бит и 8 итерационных ключей по 32 бита,
+/// которые всегда вычисляются из K. Байты хранятся в little-endian, как и во всем крейте.
+/// Ключ затирается при удалении, не выводится через Debug и копируется только явно.
+#[derive(Debug)]
+pub struct MagmaKey {
+    master: Secret<[u8; 32]>,
+    round_keys: Secret<[u32; 8]>,
+}
+
+#[allow(dead_code)]
+impl MagmaKey {
+    /// Создание ключа из мастер-ключа K с вычислением итерационных ключей
+    pub fn new(key: &[u8; 32]) -> Self {
+        Self {
+            master: Secret::new(*key),
+            round_keys: Secret::new(Self::expand(key)),
+        }
+    }
+
+    /// Генерация случайного мастер-ключа
+    pub fn generate() -> Self {
+        Self::new(random_master_key().expose())
+    }
+
+    /// Создание ключа из строки из 64 hex-символов в том виде, в котором ключ
+    /// отображается в GUI и записывается в файл (big-endian).
+    pub fn from_hex(hex: &str) -> Result<Self, String> {
+        Ok(Self::new(master_key_from_hex(hex)?.expose()))
+    }
+
+    /// Загрузка ключа из файла ключа (см. key_file): проверяются версия, алгоритм,
+    /// контрольная сумма, параметры и пароль (для защищенных файлов),
+    /// итерационные ключи вычисляются заново из K.
+    pub fn from_file(path: &Path, password: Option<&str>) -> Result<(Self, KeyFileMeta), String> {
+        let (master, meta) = read_key_file(path, "Magma", Magma::BLOCK_SIZE, password)?;
+
+        Ok((Self::new(master.expose()), meta))
+    }
+
+    /// Запись мастер-ключа и метаданных в файл ключа (итерационные ключи не записываются).
+    /// Если передан пароль, мастер-ключ записывается зашифрованным.
+    pub fn save_to_file(&self, path: &Path, meta: &KeyFileMeta, password: Option<&str>) -> Result<PathBuf, String> {
+        write_key_file(path, "Magma", Magma::BLOCK_SIZE, self.master_key(), meta, password)
+    }
+
+    /// Мастер-ключ K
+    pub fn master_key(&self) -> &[u8; 32] {
+        self.master.expose()
+    }
+
+    /// Итерационные ключи K1..K8 (ключи K9..K32 повторяют их по п. 5.3 ГОСТ Р 34.12-2018)
+    pub fn round_keys(&self) -> &[u32; 8] {
+        self.round_keys.expose()
+    }
+
+    /// Явное копирование ключа
+    pub fn clone_secret(&self) -> Self {
+        Self {
+            master: self.master.clone_secret(),
+            round_keys: self.round_keys.clone_secret(),
+        }
+    }
+
+    // Развертка ключа по п. 5.3 ГОСТ Р 34.12-2018: K1 = k_255..k_224, ..., K8 = k_31..k_0
+    fn expand(k: &[u8; 32]) -> [u32; 8] {
+        let mut round_keys: [u32; 8] = [0; 8];
+
+        for (idx, key) in round_keys.iter_mut().enumerate() {
+            let mut bytes: [u8; 4] = [0; 4];
+            bytes.copy_from_slice(&k[(28 - 4 * idx)..(32 - 4 * idx)]);
+
+            *key = u32::from_le_bytes(bytes);
+            bytes.zeroize();
+        }
+
+        round_keys
+    }
+}
+
+impl TryFrom<&[u8]> for MagmaKey {
+    type Error = String;
+
+    /// Создание ключа из среза байтов с проверкой длины (32 байта)
+    fn try_from(key: &[u8]) -> Result<Self, Self::Error> {
+        Ok(Self::new(master_key_from_slice(key)?.expose()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::self_test::MAGMA_VECTORS;
+
+    const K_GOST: &str = MAGMA_VECTORS.key;
+
+    #[test]
+    fn test_round_keys() {
+        let key = MagmaKey::from_hex(K_GOST).unwrap();
+
+        // Итерационные ключи K1..K8 из ГОСТ Р 34.12-2018 А.2.3
+        let correct: [u32; 8] = [
+            0xffeeddcc, 0xbbaa9988, 0x77665544, 0x33221100, 0xf0f1f2f3, 0xf4f5f6f7, 0xf8f9fafb,
+            0xfcfdfeff,
+        ];
+
+        assert_eq!(key.round_keys(), &correct);
+        assert!(MagmaKey::from_hex(&K_GOST[8..]).is_err());
+    }
+
+    #[test]
+    fn test_key_file() {
+        let path = std::env::temp_dir().join(format!("magma_key_test_{}", std::process::id()));
+
+        let key = MagmaKey::generate();
+        let meta = KeyFileMeta::new("user", 64, 2, &[0xCD; 16]);
+        key.save_to_file(&path, &meta, None).unwrap();
+
+        let (loaded, loaded_meta) = MagmaKey::from_file(&path, None).unwrap();
+        assert_eq!(key.master_key(), loaded.master_key());
+        assert_eq!(key.round_keys(), loaded.round_keys());
+        assert_eq!(loaded_meta, meta);
+
+        // Обрезанный файл
+        let text = std::fs::read_to_string(&path).unwrap();
+        std::fs::write(&path, &text[..text.len() / 2]).unwrap();
+
+        assert!(MagmaKey::from_file(&path, None).is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,160 @@
+use crate::algorithms::{magma::consts::MAGMA_PI, BlockCipher, FromMasterKey};
+
+pub mod consts;
+mod key;
+
+pub use key::MagmaKey;
+
+/// Блочный шифр Магма (ГОСТ Р 34.12-2018, раздел 5) с длиной блока 64 бита
+/// и длиной ключа 256 бит.
+#[derive(Debug)]
+pub struct Magma {
+    pub keys: MagmaKey,
+}
+
+#[allow(dead_code)]
+impl Magma {
+    // Создает структуру со случайным ключом
+    pub fn new() -> Self {
+        Self {
+            keys: MagmaKey::generate(),
+        }
+    }
+
+    /// Создает структуру с переданным ключом
+    pub fn with_key(keys: MagmaKey) -> Self {
+        Self { keys }
+    }
+
+    /// Явное копирование ключей. Clone не реализован, чтобы ключи
+    /// не копировались незаметно.
+    pub fn clone_secret(&self) -> Self {
+        Self::with_key(self.keys.clone_secret())
+    }
+
+    // Formula (13) page 6 in ГОСТ Р 34.12-2018: t(a) = Pi_7(a_7)||...||Pi_0(a_0)
+    fn t(a: u32) -> u32 {
+        let mut res: u32 = 0;
+
+        for (idx, pi) in MAGMA_PI.iter().enumerate() {
+            let nibble = (a >> (4 * idx)) & 0x0F;
+            res |= (pi[nibble as usize] as u32) << (4 * idx);
+        }
+
+        res
+    }
+
+    // Formula (14) page 6 in ГОСТ Р 34.12-2018: g[k](a) = (t(Vec_32(Int_32(a) + Int_32(k)))) <<< 11
+    fn g(k: u32, a: u32) -> u32 {
+        Self::t(a.wrapping_add(k)).rotate_left(11)
+    }
+
+    // Итерационный ключ K_i, i = 1..32 (п. 5.3 ГОСТ Р 34.12-2018)
+    fn round_key(&self, i: usize) -> u32 {
+        let keys = self.keys.round_keys();
+
+        if i <= 24 {
+            keys[(i - 1) % 8]
+        } else {
+            keys[7 - (i - 1) % 8]
+        }
+    }
+
+    // G*[K_32]G[K_31]...G[K_1](a_1, a_0) для последовательности ключей round_keys
+    fn feistel(&self, block: u64, round_keys: impl Iterator<Item = usize>) -> u64 {
+        let mut a1 = (block >> 32) as u32;
+        let mut a0 = block as u32;
+
+        // Formula (15) page 6: G[k](a_1, a_0) = (a_0, g[k](a_0) xor a_1)
+        for i in round_keys {
+            (a1, a0) = (a0, Self::g(self.round_key(i), a0) ^ a1);
+        }
+
+        // Formula (16) page 6: G*[k](a_1, a_0) = (g[k](a_0) xor a_1)||a_0, последний раунд без перестановки
+        ((a0 as u64) << 32) | (a1 as u64)
+    }
+
+    /// Шифрование Message блоками длины 64 бит
+    pub fn encrypt(&self, message: &[u8]) -> Result<[u8; 8], String> {
+        if message.len() * 8 != 64 {
+            return Err("Message must be len = 64 bits".to_string());
+        }
+
+        let mut a: [u8; 8] = [0; 8];
+        a.copy_from_slice(message);
+
+        Ok(self.feistel(u64::from_le_bytes(a), 1..=32).to_le_bytes())
+    }
+
+    /// Расшифрование M по блокам длины 64
+    pub fn decrypt(&self, message: &[u8]) -> Result<[u8; 8], String> {
+        if message.len() * 8 != 64 {
+            return Err("Message must be len = 64 bits".to_string());
+        }
+
+        let mut a: [u8; 8] = [0; 8];
+        a.copy_from_slice(message);
+
+        Ok(self.feistel(u64::from_le_bytes(a), (1..=32).rev()).to_le_bytes())
+    }
+}
+
+impl BlockCipher for Magma {
+    const BLOCK_SIZE: usize = 8;
+
+    fn encrypt_block(&self, block: &mut [u8]) {
+        let a: &mut [u8; 8] = block.try_into().expect("Длина блока Магмы 8 байт");
+        *a = self.feistel(u64::from_le_bytes(*a), 1..=32).to_le_bytes();
+    }
+
+    fn decrypt_block(&self, block: &mut [u8]) {
+        let a: &mut [u8; 8] = block.try_into().expect("Длина блока Магмы 8 байт");
+        *a = self.feistel(u64::from_le_bytes(*a), (1..=32).rev()).to_le_bytes();
+    }
+}
+
+impl FromMasterKey for Magma {
+    fn from_master_key(key: &[u8; 32]) -> Self {
+        Self::with_key(MagmaKey::new(key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::self_test::{le_blocks, MAGMA_VECTORS};
+
+    fn gost_key() -> Magma {
+        Magma::with_key(MagmaKey::from_hex(MAGMA_VECTORS.key).unwrap())
+    }
+
+    #[test]
+    fn test_t_transform() {
+        // ГОСТ Р 34.12-2018 А.2.1
+        assert_eq!(Magma::t(0xfdb97531), 0x2a196f34);
+        assert_eq!(Magma::t(0x2a196f34), 0xebd9f03a);
+        assert_eq!(Magma::t(0xebd9f03a), 0xb039bb3d);
+        assert_eq!(Magma::t(0xb039bb3d), 0x68695433);
+    }
+
+    #[test]
+    fn test_g_transform() {
+        // ГОСТ Р 34.12-2018 А.2.2
+        assert_eq!(Magma::g(0x87654321, 0xfedcba98), 0xfdcbc20c);
+        assert_eq!(Magma::g(0xfdcbc20c, 0x87654321), 0x7e791a4b);
+        assert_eq!(Magma::g(0x7e791a4b, 0xfdcbc20c), 0xc76549ec);
+        assert_eq!(Magma::g(0xc76549ec, 0x7e791a4b), 0x9791c849);
+    }
+
+    #[test]
+    fn test_encryption_decryption_magma() {
+        let keys = gost_key();
+
+        let (message, cipher_text) = MAGMA_VECTORS.block;
+        let (message, cipher_text) = (le_blocks(&[message]), le_blocks(&[cipher_text]));
+
+        assert_eq!(keys.encrypt(&message).unwrap().to_vec(), cipher_text);
+        assert_eq!(keys.decrypt(&cipher_text).unwrap().to_vec(), message);
+        assert!(keys.encrypt(&message[1..]).is_err());
+    }
+}
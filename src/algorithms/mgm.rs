@@ -0,0 +1,342 @@
+use zeroize::Zeroize;
+
+use super::{kuznechik::Kuznechik, BlockCipher};
+
+/// Режим аутентифицированного шифрования MGM (Multilinear Galois Mode)
+/// по Р 1323565.1.026-2019 (RFC 9058) для блочных шифров с блоком 64 или 128 бит.
+/// Данные, как и во всем крейте, разбиваются на блоки по n байт в little-endian;
+/// неполный последний блок занимает старшие байты блока.
+#[allow(dead_code)]
+pub struct Mgm<C: BlockCipher = Kuznechik> {
+    cipher: C,
+}
+
+#[allow(dead_code)]
+impl<C: BlockCipher> Mgm<C> {
+    /// Создание режима MGM на блочном шифре cipher (вместе с его ключом)
+    pub fn new(cipher: C) -> Result<Self, String> {
+        if C::BLOCK_SIZE != 8 && C::BLOCK_SIZE != 16 {
+            return Err(format!("MGM не определен для блока длины {} бит", C::BLOCK_SIZE * 8));
+        }
+
+        Ok(Self { cipher })
+    }
+
+    /// Зашифрование plaintext с ассоциированными данными ad (только имитозащита) на nonce
+    /// длины n байт (старший бит должен быть равен 0). Возвращает шифротекст и имитовставку
+    /// длины s бит (s кратно 8, 32 <= s <= n*8). Nonce не должен повторяться для одного ключа.
+    pub fn encrypt(&self, nonce: &[u8], ad: &[u8], plaintext: &[u8], s: usize) -> Result<(Vec<u8>, Vec<u8>), String> {
+        self.check_params(nonce, ad, plaintext, s)?;
+
+        let ciphertext = self.crypt(nonce, plaintext);
+        let tag = self.tag(nonce, ad, &ciphertext, s);
+
+        Ok((ciphertext, tag))
+    }
+
+    /// Расшифрование ciphertext с проверкой имитовставки tag по ассоциированным данным ad
+    /// и шифротексту. Если имитовставка не совпадает, открытый текст не вычисляется.
+    pub fn decrypt(&self, nonce: &[u8], ad: &[u8], ciphertext: &[u8], tag: &[u8]) -> Result<Vec<u8>, String> {
+        let s = tag.len() * 8;
+        self.check_params(nonce, ad, ciphertext, s)?;
+
+        // Сравнение без раннего выхода
+        let expected = self.tag(nonce, ad, ciphertext, s);
+        let diff = expected.iter().zip(tag).fold(0u8, |acc, (a, b)| acc | (a ^ b));
+
+        if diff != 0 {
+            return Err("Имитовставка не совпадает: данные или ассоциированные данные изменены".to_string());
+        }
+
+        Ok(self.crypt(nonce, ciphertext))
+    }
+
+    fn check_params(&self, nonce: &[u8], ad: &[u8], text: &[u8], s: usize) -> Result<(), String> {
+        let n = C::BLOCK_SIZE;
+
+        if nonce.len() != n {
+            return Err(format!("Длина nonce должна быть {} байт", n));
+        }
+
+        // Nonce - строка длины n-1 бит, старший бит блока занят под 0||nonce и 1||nonce
+        if nonce[n - 1] & 0x80 != 0 {
+            return Err("Старший бит nonce должен быть равен 0".to_string());
+        }
+
+        if !s.is_multiple_of(8) || s < 32 || s > n * 8 {
+            return Err(format!("Длина имитовставки должна быть кратна 8 и лежать в [32; {}]", n * 8));
+        }
+
+        if ad.is_empty() && text.is_empty() {
+            return Err("Ассоциированные данные и текст не могут быть пустыми одновременно".to_string());
+        }
+
+        // Длины в битах записываются в n/2 бит
+        let max_bits = 1u128 << (n * 4);
+        if (ad.len() as u128 + text.len() as u128) * 8 >= max_bits {
+            return Err("Слишком большая длина данных для MGM".to_string());
+        }
+
+        Ok(())
+    }
+
+    // Y_1 = e_K(0||nonce), Y_(i+1) = incr_r(Y_i), C_i = P_i xor MSB_|P_i|(e_K(Y_i))
+    fn crypt(&self, nonce: &[u8], text: &[u8]) -> Vec<u8> {
+        let n = C::BLOCK_SIZE;
+
+        let mut y = nonce.to_vec();
+        y[n - 1] &= 0x7F;
+        self.cipher.encrypt_block(&mut y);
+
+        let mut res = Vec::with_capacity(text.len());
+        let mut gamma = vec![0u8; n];
+
+        for chunk in text.chunks(n) {
+            gamma.copy_from_slice(&y);
+            self.cipher.encrypt_block(&mut gamma);
+
+            // Неполный блок складывается со старшими байтами гаммы
+            res.extend(chunk.iter().zip(&gamma[n - chunk.len()..]).map(|(p, g)| p ^ g));
+
+            incr(&mut y[..n / 2]);
+        }
+
+        gamma.zeroize();
+        y.zeroize();
+
+        res
+    }
+
+    // T = MSB_s(e_K(sum H_i (x) A_i + sum H_(h+j) (x) C_j + H_(h+q+1) (x) (len(A)||len(C)))),
+    // Z_1 = e_K(1||nonce), Z_(i+1) = incr_l(Z_i), H_i = e_K(Z_i)
+    fn tag(&self, nonce: &[u8], ad: &[u8], ciphertext: &[u8], s: usize) -> Vec<u8> {
+        let n = C::BLOCK_SIZE;
+
+        let mut z = nonce.to_vec();
+        z[n - 1] |= 0x80;
+        self.cipher.encrypt_block(&mut z);
+
+        let mut sum: u128 = 0;
+        let mut h = vec![0u8; n];
+
+        // Длины A и C в битах: len(A) - старшая половина блока, len(C) - младшая
+        let mut lengths = vec![0u8; n];
+        lengths[..n / 2].copy_from_slice(&((ciphertext.len() * 8) as u128).to_le_bytes()[..n / 2]);
+        lengths[n / 2..].copy_from_slice(&((ad.len() * 8) as u128).to_le_bytes()[..n / 2]);
+
+        let blocks = ad.chunks(n).chain(ciphertext.chunks(n)).chain([&lengths[..]]);
+
+        for block in blocks {
+            h.copy_from_slice(&z);
+            self.cipher.encrypt_block(&mut h);
+
+            // Дополнение неполного блока нулями справа (в младших байтах)
+            let mut padded = [0u8; 16];
+            padded[n - block.len()..n].copy_from_slice(block);
+
+            sum ^= gf_mul(to_u128(&h), u128::from_le_bytes(padded), n);
+
+            incr(&mut z[n / 2..]);
+        }
+
+        let mut tag = sum.to_le_bytes()[..n].to_vec();
+        self.cipher.encrypt_block(&mut tag);
+
+        h.zeroize();
+        z.zeroize();
+
+        tag[n - s / 8..].to_vec()
+    }
+}
+
+// Увеличение половины блока на 1 по модулю 2^(n/2) (половина в little-endian)
+fn incr(half: &mut [u8]) {
+    for byte in half.iter_mut() {
+        let (res, overflow) = byte.overflowing_add(1);
+        *byte = res;
+
+        if !overflow {
+            break;
+        }
+    }
+}
+
+fn to_u128(block: &[u8]) -> u128 {
+    let mut bytes = [0u8; 16];
+    bytes[..block.len()].copy_from_slice(block);
+
+    u128::from_le_bytes(bytes)
+}
+
+// Умножение в GF(2^128) по модулю x^128 + x^7 + x^2 + x + 1 или в GF(2^64)
+// по модулю x^64 + x^4 + x^3 + x + 1 (n = 16 или 8 байт) без ветвлений по данным
+fn gf_mul(a: u128, b: u128, n: usize) -> u128 {
+    let bits = n * 8;
+    let (mask, poly): (u128, u128) = if n == 16 { (u128::MAX, 0x87) } else { (u64::MAX as u128, 0x1B) };
+
+    let mut a = a;
+    let mut res: u128 = 0;
+
+    for i in 0..bits {
+        res ^= a & 0u128.wrapping_sub((b >> i) & 1);
+
+        let carry = (a >> (bits - 1)) & 1;
+        a = ((a << 1) & mask) ^ (poly & 0u128.wrapping_sub(carry));
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::{
+        hex_to_bytes,
+        kuznechik::KuznechikKey,
+        magma::{Magma, MagmaKey},
+        random_vec,
+    };
+
+    // Блоки из big-endian записи стандарта в little-endian
+    fn blocks(blocks: &[&str]) -> Vec<u8> {
+        let mut res = vec![];
+        for block in blocks {
+            let mut block = hex_to_bytes(block);
+            block.reverse();
+            res.extend(block);
+        }
+
+        res
+    }
+
+    #[test]
+    fn test_mgm_kuznechik_rfc9058() {
+        let key = KuznechikKey::from_hex("8899aabbccddeeff0011223344556677fedcba98765432100123456789abcdef").unwrap();
+        let mgm = Mgm::new(Kuznechik::with_key(key)).unwrap();
+
+        // Р 1323565.1.026-2019, приложение А.1 (RFC 9058, A.1)
+        let nonce = blocks(&["1122334455667700ffeeddccbbaa9988"]);
+        let ad = blocks(&[
+            "02020202020202020101010101010101",
+            "04040404040404040303030303030303",
+            "ea0505050505050505",
+        ]);
+        let p = blocks(&[
+            "1122334455667700ffeeddccbbaa9988",
+            "00112233445566778899aabbcceeff0a",
+            "112233445566778899aabbcceeff0a00",
+            "2233445566778899aabbcceeff0a0011",
+            "aabbcc",
+        ]);
+        let c = blocks(&[
+            "a9757b8147956e9055b8a33de89f42fc",
+            "8075d2212bf9fd5bd3f7069aadc16b39",
+            "497ab15915a6ba85936b5d0ea9f6851c",
+            "c60c14d4d3f883d0ab94420695c76deb",
+            "2c7552",
+        ]);
+        let t = blocks(&["cf5d656f40c34f5c46e8bb0e29fcdb4c"]);
+
+        let (res, tag) = mgm.encrypt(&nonce, &ad, &p, 128).unwrap();
+        assert_eq!(res, c);
+        assert_eq!(tag, t);
+        assert_eq!(mgm.decrypt(&nonce, &ad, &c, &t).unwrap(), p);
+
+        // Укороченная имитовставка - старшие байты полной
+        let (_, short_tag) = mgm.encrypt(&nonce, &ad, &p, 64).unwrap();
+        assert_eq!(short_tag, t[8..].to_vec());
+        assert_eq!(mgm.decrypt(&nonce, &ad, &c, &short_tag).unwrap(), p);
+
+        // Измененные шифротекст, ассоциированные данные или имитовставка отклоняются
+        let mut bad_c = c.clone();
+        bad_c[5] ^= 1;
+        assert!(mgm.decrypt(&nonce, &ad, &bad_c, &t).is_err());
+
+        let mut bad_ad = ad.clone();
+        bad_ad[0] ^= 0x80;
+        assert!(mgm.decrypt(&nonce, &bad_ad, &c, &t).is_err());
+
+        let mut bad_t = t.clone();
+        bad_t[15] ^= 1;
+        assert!(mgm.decrypt(&nonce, &ad, &c, &bad_t).is_err());
+        assert!(mgm.decrypt(&nonce, &ad, &c[..c.len() - 1], &t).is_err());
+    }
+
+    #[test]
+    fn test_mgm_magma_rfc9058() {
+        let key = MagmaKey::from_hex("ffeeddccbbaa99887766554433221100f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff").unwrap();
+        let mgm = Mgm::new(Magma::with_key(key)).unwrap();
+
+        // Р 1323565.1.026-2019, приложение А.2 (RFC 9058, A.2)
+        let nonce = blocks(&["12def06b3c130a59"]);
+        let ad = blocks(&[
+            "0101010101010101",
+            "0202020202020202",
+            "0303030303030303",
+            "0404040404040404",
+            "0505050505050505",
+            "ea",
+        ]);
+        let p = blocks(&[
+            "ffeeddccbbaa9988",
+            "1122334455667700",
+            "8899aabbcceeff0a",
+            "0011223344556677",
+            "99aabbcceeff0a00",
+            "1122334455667788",
+            "aabbcceeff0a0011",
+            "2233445566778899",
+            "aabbcc",
+        ]);
+        let c = blocks(&[
+            "c795066c5f9ea03b",
+            "85113342459185ae",
+            "1f2e00d6bf2b785d",
+            "940470b8bb9c8e7d",
+            "9a5dd3731f7ddc70",
+            "ec27cb0ace6fa576",
+            "70f65c646abb75d5",
+            "47aa37c3bcb5c34e",
+            "03bb9c",
+        ]);
+        let t = blocks(&["a7928069aa10fd10"]);
+
+        let (res, tag) = mgm.encrypt(&nonce, &ad, &p, 64).unwrap();
+        assert_eq!(res, c);
+        assert_eq!(tag, t);
+        assert_eq!(mgm.decrypt(&nonce, &ad, &c, &t).unwrap(), p);
+
+        let mut bad_t = t.clone();
+        bad_t[0] ^= 1;
+        assert!(mgm.decrypt(&nonce, &ad, &c, &bad_t).is_err());
+    }
+
+    #[test]
+    fn test_mgm_params_and_magma() {
+        let mgm = Mgm::new(Magma::with_key(MagmaKey::generate())).unwrap();
+
+        let mut nonce = random_vec(8);
+        nonce[7] &= 0x7F;
+
+        for len in [0, 1, 7, 8, 9, 100] {
+            let ad = random_vec(len / 2);
+            let p = random_vec(len);
+
+            if len == 0 {
+                assert!(mgm.encrypt(&nonce, &ad, &p, 64).is_err());
+                continue;
+            }
+
+            let (c, tag) = mgm.encrypt(&nonce, &ad, &p, 64).unwrap();
+            assert_eq!(mgm.decrypt(&nonce, &ad, &c, &tag).unwrap(), p);
+        }
+
+        // Старший бит nonce, длина nonce и имитовставки
+        let mut bad_nonce = nonce.clone();
+        bad_nonce[7] |= 0x80;
+        assert!(mgm.encrypt(&bad_nonce, &[], &[1], 64).is_err());
+        assert!(mgm.encrypt(&nonce[1..], &[], &[1], 64).is_err());
+        assert!(mgm.encrypt(&nonce, &[], &[1], 24).is_err());
+        assert!(mgm.encrypt(&nonce, &[], &[1], 72).is_err());
+    }
+}
@@ -1,10 +1,122 @@
 use rand::Rng;
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Mutex,
+};
 
 pub mod block_cipher_modes;
+pub mod ctr_cmac;
+pub mod kdf;
+pub mod key_file;
+pub mod key_wrap;
 pub mod kuznechik;
+pub mod magma;
+pub mod mgm;
+pub mod pbkdf2;
 pub mod rsa;
+pub mod secret;
+pub mod self_test;
 pub mod streebog;
 
+pub use self_test::self_test;
+
+/// Блочный шифр с длиной блока BLOCK_SIZE байт. Все режимы ГОСТ Р 34.13-2018
+/// (block_cipher_modes) и CMAC реализованы для любого типа с этим трейтом.
+/// Блоки передаются в little-endian, как и во всем крейте.
+pub trait BlockCipher {
+    /// Длина блока в байтах (n / 8)
+    const BLOCK_SIZE: usize;
+
+    /// Зашифрование одного блока на месте. Длина block должна быть равна BLOCK_SIZE,
+    /// иначе паника.
+    fn encrypt_block(&self, block: &mut [u8]);
+
+    /// Расшифрование одного блока на месте. Длина block должна быть равна BLOCK_SIZE,
+    /// иначе паника.
+    fn decrypt_block(&self, block: &mut [u8]);
+
+    /// Зашифрование на месте последовательности блоков, записанных подряд.
+    /// Длина blocks должна быть кратна BLOCK_SIZE, иначе паника.
+    fn encrypt_blocks(&self, blocks: &mut [u8]) {
+        assert!(blocks.len().is_multiple_of(Self::BLOCK_SIZE), "Длина должна быть кратна длине блока");
+
+        for block in blocks.chunks_exact_mut(Self::BLOCK_SIZE) {
+            self.encrypt_block(block);
+        }
+    }
+
+    /// Расшифрование на месте последовательности блоков, записанных подряд.
+    /// Длина blocks должна быть кратна BLOCK_SIZE, иначе паника.
+    fn decrypt_blocks(&self, blocks: &mut [u8]) {
+        assert!(blocks.len().is_multiple_of(Self::BLOCK_SIZE), "Длина должна быть кратна длине блока");
+
+        for block in blocks.chunks_exact_mut(Self::BLOCK_SIZE) {
+            self.decrypt_block(block);
+        }
+    }
+}
+
+/// Блочный шифр с ключом 256 бит, который можно создать по мастер-ключу K
+/// (в little-endian). Нужен режимам со сменой ключа ACPKM (Р 1323565.1.017-2018).
+pub trait FromMasterKey: BlockCipher {
+    fn from_master_key(key: &[u8; 32]) -> Self;
+}
+
+/// Параллельное зашифрование блоков, записанных подряд: blocks делится на
+/// отрезки из целого числа блоков по числу ядер, каждый отрезок обрабатывается
+/// в отдельном потоке. Результат совпадает с BlockCipher::encrypt_blocks.
+pub fn par_encrypt_blocks<C: BlockCipher + Sync>(cipher: &C, blocks: &mut [u8]) {
+    par_segments(blocks, C::BLOCK_SIZE, &|_, segment| cipher.encrypt_blocks(segment));
+}
+
+/// Параллельное расшифрование блоков, записанных подряд (см. par_encrypt_blocks)
+pub fn par_decrypt_blocks<C: BlockCipher + Sync>(cipher: &C, blocks: &mut [u8]) {
+    par_segments(blocks, C::BLOCK_SIZE, &|_, segment| cipher.decrypt_blocks(segment));
+}
+
+/// Делит data на отрезки длины, кратной n байт, по числу доступных ядер и вызывает
+/// f(смещение отрезка в байтах, отрезок) для каждого из них в отдельном потоке.
+pub fn par_segments(data: &mut [u8], n: usize, f: &(dyn Fn(usize, &mut [u8]) + Sync)) {
+    let threads = std::thread::available_parallelism().map(|t| t.get()).unwrap_or(1);
+    let segment_len = data.len().div_ceil(n).div_ceil(threads).max(1) * n;
+
+    std::thread::scope(|scope| {
+        for (idx, segment) in data.chunks_mut(segment_len).enumerate() {
+            scope.spawn(move || f(idx * segment_len, segment));
+        }
+    });
+}
+
+/// Делит data на отрезки длины segment_len байт (последний может быть короче) и обрабатывает их
+/// в потоках по числу доступных ядер: каждый поток берет следующий необработанный отрезок и вызывает
+/// f(номер отрезка, отрезок), после чего вызывается progress(обработано байт, всего байт).
+pub fn par_segments_progress(
+    data: &mut [u8],
+    segment_len: usize,
+    f: &(dyn Fn(usize, &mut [u8]) + Sync),
+    progress: &(dyn Fn(usize, usize) + Sync),
+) {
+    let threads = std::thread::available_parallelism().map(|t| t.get()).unwrap_or(1);
+    let total = data.len();
+    let done = AtomicUsize::new(0);
+    let segments = Mutex::new(data.chunks_mut(segment_len.max(1)).enumerate());
+
+    std::thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| loop {
+                // Блокировка снимается до обработки отрезка
+                let next = segments.lock().map(|mut segments| segments.next());
+                let Ok(Some((idx, segment))) = next else {
+                    break;
+                };
+
+                f(idx, segment);
+                progress(done.fetch_add(segment.len(), Ordering::Relaxed) + segment.len(), total);
+            });
+        }
+    });
+}
+
 #[allow(dead_code)]
 /// Печатает символы из байтовой строки с конца. Используется функция для
 /// провеки значений при отладке и тестах.
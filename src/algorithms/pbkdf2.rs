@@ -0,0 +1,167 @@
+use zeroize::Zeroize;
+
+use super::{kuznechik::KuznechikKey, random_vec, secret::Secret, streebog::hmac::HmacStreebog512};
+
+// Длина выхода HMAC_GOSTR3411_2012_512 в байтах
+const H_LEN: usize = 64;
+
+/// Метка формата хранимого значения для проверки пароля
+pub const VERIFIER_PREFIX: &str = "pbkdf2-streebog512";
+
+/// PBKDF2 с псевдослучайной функцией HMAC_GOSTR3411_2012_512 (Р 50.1.111-2016):
+/// DK = T_1 || T_2 || ..., T_i = U_1 ^ ... ^ U_c, U_1 = HMAC(P, S || INT(i)), U_j = HMAC(P, U_(j-1)),
+/// где INT(i) - номер блока в 4 байтах big-endian. Пароль, соль и результат - строки байтов
+/// в порядке передачи. Возвращает dk_len байт, iterations (c) и dk_len должны быть не меньше 1.
+pub fn pbkdf2(password: &[u8], salt: &[u8], iterations: u32, dk_len: usize) -> Result<Secret<Vec<u8>>, String> {
+    if iterations < 1 {
+        return Err("Число итераций PBKDF2 должно быть не меньше 1".to_string());
+    }
+
+    if dk_len < 1 || dk_len.div_ceil(H_LEN) > u32::MAX as usize {
+        return Err("Некорректная длина ключа PBKDF2".to_string());
+    }
+
+    // Ключ HMAC обрабатывается один раз, для каждого U копируется готовое состояние
+    let prf = HmacStreebog512::new(password);
+    let mut dk = Secret::new(Vec::with_capacity(dk_len.next_multiple_of(H_LEN)));
+
+    for idx in 1..=dk_len.div_ceil(H_LEN) as u32 {
+        let mut hmac = prf.clone();
+        hmac.update(salt);
+        hmac.update(&idx.to_be_bytes());
+
+        let mut u = hmac.finalize();
+        let mut t = u.clone();
+
+        for _ in 1..iterations {
+            let mut hmac = prf.clone();
+            hmac.update(&u);
+            u.zeroize();
+            u = hmac.finalize();
+
+            t.iter_mut().zip(&u).for_each(|(t, u)| *t ^= u);
+        }
+
+        dk.expose_mut().extend_from_slice(&t);
+        u.zeroize();
+        t.zeroize();
+    }
+
+    dk.expose_mut().truncate(dk_len);
+
+    Ok(dk)
+}
+
+/// Ключ Кузнечика из парольной фразы: 256 бит PBKDF2 с солью salt и числом итераций iterations
+#[allow(dead_code)]
+pub fn kuznechik_key_from_passphrase(passphrase: &str, salt: &[u8], iterations: u32) -> Result<KuznechikKey, String> {
+    KuznechikKey::try_from(&pbkdf2(passphrase.as_bytes(), salt, iterations, 32)?.expose()[..])
+}
+
+/// Хранимое значение для проверки пароля со случайной солью 16 байт:
+/// "pbkdf2-streebog512$c$соль$DK", где соль и DK (64 байта) записаны в hex
+#[allow(dead_code)]
+pub fn password_verifier(password: &str, iterations: u32) -> Result<String, String> {
+    let salt = random_vec(16);
+    let dk = pbkdf2(password.as_bytes(), &salt, iterations, H_LEN)?;
+
+    Ok(format!("{}${}${}${}", VERIFIER_PREFIX, iterations, hex::encode(&salt), hex::encode(dk.expose())))
+}
+
+/// Проверка пароля по значению из password_verifier. DK сравниваются без раннего выхода,
+/// время не зависит от числа совпавших байтов.
+#[allow(dead_code)]
+pub fn verify_password(password: &str, verifier: &str) -> Result<bool, String> {
+    let parts: Vec<&str> = verifier.trim().split('$').collect();
+
+    let (iterations, salt, expected) = match parts[..] {
+        [VERIFIER_PREFIX, iterations, salt, dk] => (iterations, salt, dk),
+        _ => return Err("Некорректное значение для проверки пароля".to_string()),
+    };
+
+    let iterations: u32 = match iterations.parse() {
+        Ok(iterations) => iterations,
+        Err(_) => return Err("Некорректное число итераций PBKDF2".to_string()),
+    };
+
+    let (salt, expected) = match (hex::decode(salt), hex::decode(expected)) {
+        (Ok(salt), Ok(expected)) if !expected.is_empty() => (salt, expected),
+        _ => return Err("Некорректное значение для проверки пароля".to_string()),
+    };
+
+    let dk = pbkdf2(password.as_bytes(), &salt, iterations, expected.len())?;
+    let diff = dk.expose().iter().zip(&expected).fold(0u8, |acc, (a, b)| acc | (a ^ b));
+
+    Ok(diff == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::hex_to_bytes;
+
+    // Р 50.1.111-2016, контрольные примеры
+    #[test]
+    fn test_pbkdf2_gost() {
+        let dk = pbkdf2(b"password", b"salt", 1, 64).unwrap();
+        assert_eq!(dk.expose(), &hex_to_bytes(concat!(
+            "64770af7f748c3b1c9ac831dbcfd85c26111b30a8a657ddc3056b80ca73e040d",
+            "2854fd36811f6d825cc4ab66ec0a68a490a9e5cf5156b3a2b7eecddbf9a16b47"
+        )));
+
+        let dk = pbkdf2(b"password", b"salt", 2, 64).unwrap();
+        assert_eq!(dk.expose(), &hex_to_bytes(concat!(
+            "5a585bafdfbb6e8830d6d68aa3b43ac00d2e4aebce01c9b31c2caed56f0236d4",
+            "d34b2b8fbd2c4e89d54d46f50e47d45bbac301571743119e8d3c42ba66d348de"
+        )));
+
+        // Префикс результата не зависит от dk_len
+        assert_eq!(pbkdf2(b"password", b"salt", 2, 10).unwrap().expose(), &dk.expose()[..10]);
+
+        assert!(pbkdf2(b"password", b"salt", 0, 64).is_err());
+        assert!(pbkdf2(b"password", b"salt", 1, 0).is_err());
+    }
+
+    // Р 50.1.111-2016, примеры с c = 4096
+    #[test]
+    fn test_pbkdf2_gost_4096() {
+        let vectors: [(&[u8], &[u8], usize, &str); 3] = [
+            (b"password", b"salt", 64, concat!(
+                "e52deb9a2d2aaff4e2ac9d47a41f34c20376591c67807f0477e32549dc341bc7",
+                "867c09841b6d58e29d0347c996301d55df0d34e47cf68f4e3c2cdaf1d9ab86c3"
+            )),
+            (b"pass\0word", b"sa\0lt", 64, concat!(
+                "50df062885b69801a3c10248eb0a27ab6e522ffeb20c991c660f001475d73a4e",
+                "167f782c18e97e92976d9c1d970831ea78ccb879f67068cdac1910740844e830"
+            )),
+            // Длина, не кратная длине блока HMAC
+            (b"passwordPASSWORDpassword", b"saltSALTsaltSALTsaltSALTsaltSALTsalt", 100, concat!(
+                "b2d8f1245fc4d29274802057e4b54e0a0753aa22fc53760b301cf008679e58fe",
+                "4bee9addcae99ba2b0b20f431a9c5e50f395c89387d0945aedeca6eb4015dfc2",
+                "bd2421ee9bb71183ba882ceebfef259f33f9e27dc6178cb89dc37428cf9cc52a",
+                "2baa2d3a"
+            )),
+        ];
+
+        for (password, salt, dk_len, dk) in vectors {
+            assert_eq!(pbkdf2(password, salt, 4096, dk_len).unwrap().expose(), &hex_to_bytes(dk));
+        }
+    }
+
+    #[test]
+    fn test_password_verifier_and_key() {
+        let verifier = password_verifier("пароль", 10).unwrap();
+        assert!(verifier.starts_with("pbkdf2-streebog512$10$"));
+        assert!(verify_password("пароль", &verifier).unwrap());
+        assert!(!verify_password("парол", &verifier).unwrap());
+
+        // Соль случайная: одинаковые пароли дают разные значения
+        assert_ne!(password_verifier("пароль", 10).unwrap(), verifier);
+
+        assert!(verify_password("пароль", "streebog$10$00$00").is_err());
+        assert!(verify_password("пароль", &verifier.replace("$10$", "$x$")).is_err());
+
+        let key = kuznechik_key_from_passphrase("парольная фраза", b"salt", 2).unwrap();
+        assert_eq!(&key.master_key()[..], pbkdf2("парольная фраза".as_bytes(), b"salt", 2, 32).unwrap().expose());
+    }
+}
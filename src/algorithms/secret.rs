@@ -0,0 +1,104 @@
+use std::fmt;
+
+use zeroize::Zeroize;
+
+/// Обертка над секретными данными (ключами, секретными параметрами).
+/// При удалении обертки память с секретом затирается нулями, а Debug
+/// не раскрывает содержимое. Обертка намеренно не реализует Clone:
+/// копия секрета создается только явным вызовом clone_secret.
+pub struct Secret<T: Zeroize>(T);
+
+#[allow(dead_code)]
+impl<T: Zeroize> Secret<T> {
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Доступ к секретному значению на чтение
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+
+    /// Доступ к секретному значению на запись
+    pub fn expose_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+impl<T: Zeroize + Clone> Secret<T> {
+    /// Явное создание копии секрета
+    pub fn clone_secret(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: Zeroize> Drop for Secret<T> {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<T: Zeroize> fmt::Debug for Secret<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret([REDACTED])")
+    }
+}
+
+impl<T: Zeroize> From<T> for Secret<T> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, rc::Rc};
+
+    use super::*;
+
+    #[test]
+    fn test_secret_debug_redacted() {
+        let secret = Secret::new(vec![0xAAu8; 32]);
+
+        assert_eq!(format!("{:?}", secret), "Secret([REDACTED])");
+        assert_eq!(secret.expose(), &vec![0xAAu8; 32]);
+    }
+
+    // Значение, которое записывает в общий счетчик каждый вызов zeroize
+    struct Recorder {
+        data: [u8; 16],
+        zeroized: Rc<Cell<usize>>,
+    }
+
+    impl Zeroize for Recorder {
+        fn zeroize(&mut self) {
+            self.data.zeroize();
+            self.zeroized.set(self.zeroized.get() + 1);
+        }
+    }
+
+    #[test]
+    fn test_secret_zeroize_on_drop() {
+        let zeroized = Rc::new(Cell::new(0));
+        let secret = Secret::new(Recorder { data: [0x55u8; 16], zeroized: zeroized.clone() });
+
+        assert_eq!(secret.expose().data, [0x55u8; 16]);
+        assert_eq!(zeroized.get(), 0);
+
+        drop(secret);
+        assert_eq!(zeroized.get(), 1);
+
+        // Копия затирается независимо от оригинала
+        let secret = Secret::new([0x55u8; 16]);
+        let copy = secret.clone_secret();
+        drop(secret);
+        assert_eq!(copy.expose(), &[0x55u8; 16]);
+
+        // Затирание при удалении содержимого, переданного через From
+        let zeroized = Rc::new(Cell::new(0));
+        {
+            let _secret: Secret<Recorder> = Recorder { data: [1u8; 16], zeroized: zeroized.clone() }.into();
+        }
+        assert_eq!(zeroized.get(), 1);
+    }
+}
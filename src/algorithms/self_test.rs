@@ -0,0 +1,315 @@
+use std::{
+    fmt,
+    panic::{self, AssertUnwindSafe},
+};
+
+use super::{
+    block_cipher_modes::{CipherModes, Padding, CMAC},
+    hex_to_bytes,
+    kuznechik::{Kuznechik, KuznechikKey},
+    magma::{Magma, MagmaKey},
+    streebog::streebog,
+    BlockCipher,
+};
+
+/// Результат проверки одного алгоритма на контрольном примере
+#[derive(Debug, Clone)]
+pub struct SelfTestCheck {
+    pub name: &'static str,
+    pub result: Result<(), String>,
+}
+
+/// Отчет самотестирования: результаты проверок всех алгоритмов по порядку
+#[derive(Debug, Clone)]
+pub struct SelfTestReport {
+    pub checks: Vec<SelfTestCheck>,
+}
+
+#[allow(dead_code)]
+impl SelfTestReport {
+    /// Все проверки пройдены
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|check| check.result.is_ok())
+    }
+
+    /// Проверки, которые не пройдены
+    pub fn failed(&self) -> impl Iterator<Item = &SelfTestCheck> {
+        self.checks.iter().filter(|check| check.result.is_err())
+    }
+}
+
+impl fmt::Display for SelfTestReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for check in &self.checks {
+            match &check.result {
+                Ok(()) => writeln!(f, "{}: OK", check.name)?,
+                Err(error) => writeln!(f, "{}: ОШИБКА ({})", check.name, error)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Контрольные примеры шифра (ГОСТ Р 34.12-2018) и его режимов (ГОСТ Р 34.13-2018, приложение А).
+/// Все значения в big-endian по блокам, как в тексте стандарта. Те же примеры используются
+/// в модульных тестах, перевод в little-endian - le_blocks.
+pub struct ModesVectors {
+    pub key: &'static str,
+    pub p: [&'static str; 4],
+    pub block: (&'static str, &'static str),
+    pub ecb: [&'static str; 4],
+    pub ctr_iv: &'static str,
+    pub ctr: [&'static str; 4],
+    pub ofb_cfb_iv: &'static str,
+    pub ofb: [&'static str; 4],
+    pub cbc_iv: &'static str,
+    pub cbc_z: usize,
+    pub cbc: [&'static str; 4],
+    pub cfb: [&'static str; 4],
+    pub mac_s: usize,
+    pub mac: &'static str,
+}
+
+/// ГОСТ Р 34.12-2018 А.1, ГОСТ Р 34.13-2018 А.1
+pub const KUZNECHIK_VECTORS: ModesVectors = ModesVectors {
+    key: "8899aabbccddeeff0011223344556677fedcba98765432100123456789abcdef",
+    p: [
+        "1122334455667700ffeeddccbbaa9988",
+        "00112233445566778899aabbcceeff0a",
+        "112233445566778899aabbcceeff0a00",
+        "2233445566778899aabbcceeff0a0011",
+    ],
+    block: ("1122334455667700ffeeddccbbaa9988", "7f679d90bebc24305a468d42b9d4edcd"),
+    ecb: [
+        "7f679d90bebc24305a468d42b9d4edcd",
+        "b429912c6e0032f9285452d76718d08b",
+        "f0ca33549d247ceef3f5a5313bd4b157",
+        "d0b09ccde830b9eb3a02c4c5aa8ada98",
+    ],
+    ctr_iv: "1234567890abcef0",
+    ctr: [
+        "f195d8bec10ed1dbd57b5fa240bda1b8",
+        "85eee733f6a13e5df33ce4b33c45dee4",
+        "a5eae88be6356ed3d5e877f13564a3a5",
+        "cb91fab1f20cbab6d1c6d15820bdba73",
+    ],
+    ofb_cfb_iv: "1234567890abcef0a1b2c3d4e5f0011223344556677889901213141516171819",
+    ofb: [
+        "81800a59b1842b24ff1f795e897abd95",
+        "ed5b47a7048cfab48fb521369d9326bf",
+        "66a257ac3ca0b8b1c80fe7fc10288a13",
+        "203ebbc066138660a0292243f6903150",
+    ],
+    cbc_iv: "1234567890abcef0a1b2c3d4e5f0011223344556677889901213141516171819",
+    cbc_z: 2,
+    cbc: [
+        "689972d4a085fa4d90e52e3d6d7dcc27",
+        "2826e661b478eca6af1e8e448d5ea5ac",
+        "fe7babf1e91999e85640e8b0f49d90d0",
+        "167688065a895c631a2d9a1560b63970",
+    ],
+    cfb: [
+        "81800a59b1842b24ff1f795e897abd95",
+        "ed5b47a7048cfab48fb521369d9326bf",
+        "79f2a8eb5cc68d38842d264e97a238b5",
+        "4ffebecd4e922de6c75bd9dd44fbf4d1",
+    ],
+    mac_s: 64,
+    mac: "336f4d296059fbe3",
+};
+
+/// ГОСТ Р 34.12-2018 А.2, ГОСТ Р 34.13-2018 А.2
+pub const MAGMA_VECTORS: ModesVectors = ModesVectors {
+    key: "ffeeddccbbaa99887766554433221100f0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+    p: ["92def06b3c130a59", "db54c704f8189d20", "4a98fb2e67a8024c", "8912409b17b57e41"],
+    block: ("fedcba9876543210", "4ee901e5c2d8ca3d"),
+    ecb: ["2b073f0494f372a0", "de70e715d3556e48", "11d8d9e9eacfbc1e", "7c68260996c67efb"],
+    ctr_iv: "12345678",
+    ctr: ["4e98110c97b7b93c", "3e250d93d6e85d69", "136d868807b2dbef", "568eb680ab52a12d"],
+    ofb_cfb_iv: "1234567890abcdef234567890abcdef1",
+    ofb: ["db37e0e266903c83", "0d46644c1f9a089c", "a0f83062430e327e", "c824efb8bd4fdb05"],
+    cbc_iv: "1234567890abcdef234567890abcdef134567890abcdef12",
+    cbc_z: 3,
+    cbc: ["96d1b05eea683919", "aff76129abb937b9", "5058b4a1c4bc0019", "20b78b1a7cd7e667"],
+    cfb: ["db37e0e266903c83", "0d46644c1f9a089c", "24bdd2035315d38b", "bcc0321421075505"],
+    mac_s: 32,
+    mac: "154e7210",
+};
+
+/// ГОСТ Р 34.11-2018 А.1 (M1) и А.2 (M2): имя, сообщение, хэш-код 512 бит, хэш-код 256 бит
+pub const STREEBOG_VECTORS: [(&str, &str, &str, &str); 2] = [
+    (
+        "Стрибог M1",
+        "323130393837363534333231303938373635343332313039383736353433323130393837363534333231303938373635343332313039383736353433323130",
+        "486f64c1917879417fef082b3381a4e211c324f074654c38823a7b76f830ad00fa1fbae42b1285c0352f227524bc9ab16254288dd6863dccd5b9f54a1ad0541b",
+        "00557be5e584fd52a449b16b0251d05d27f94ab76cbaa6da890b59d8ef1e159d",
+    ),
+    (
+        "Стрибог M2",
+        "fbe2e5f0eee3c820fbeafaebef20fffbf0e1e0f0f520e0ed20e8ece0ebe5f0f2f120fff0eeec20f120faf2fee5e2202ce8f6f3ede220e8e6eee1e8f0f2d1202ce8f0f2e5e220e5d1",
+        "28fbc9bada033b1460642bdcddb90c3fb3e56c497ccd0f62b8a2ad4935e85f037613966de4ee00531ae60f3b5a47f8dae06915d5f2f194996fcabf2622e6881e",
+        "508f7e553c06501d749a66fc28c6cac0b005746d97537fa85d9e40904efed29d",
+    ),
+];
+
+/// Самотестирование: проверка Кузнечика и Магмы (ГОСТ Р 34.12-2018), их режимов и
+/// имитовставки (ГОСТ Р 34.13-2018) и Стрибога (ГОСТ Р 34.11-2018) на контрольных примерах
+/// из стандартов. Паника внутри алгоритма считается непройденной проверкой.
+pub fn self_test() -> SelfTestReport {
+    let mut checks = vec![];
+
+    checks.extend(check_cipher(
+        ["Кузнечик", "Кузнечик ECB", "Кузнечик CTR", "Кузнечик OFB", "Кузнечик CBC", "Кузнечик CFB", "Кузнечик MAC"],
+        &KUZNECHIK_VECTORS,
+        |vectors| Ok(Kuznechik::with_key(KuznechikKey::from_hex(vectors.key)?)),
+    ));
+
+    checks.extend(check_cipher(
+        ["Магма", "Магма ECB", "Магма CTR", "Магма OFB", "Магма CBC", "Магма CFB", "Магма MAC"],
+        &MAGMA_VECTORS,
+        |vectors| Ok(Magma::with_key(MagmaKey::from_hex(vectors.key)?)),
+    ));
+
+    for (name, message, hash512, hash256) in STREEBOG_VECTORS {
+        checks.push(run_check(name, || {
+            let message = le_blocks(&[message]);
+
+            check("хэш-код 512 бит", &streebog(&message, 512)?, &le_blocks(&[hash512]))?;
+            check("хэш-код 256 бит", &streebog(&message, 256)?, &le_blocks(&[hash256]))
+        }));
+    }
+
+    SelfTestReport { checks }
+}
+
+// Проверки блочного шифра и всех режимов на контрольных примерах
+fn check_cipher<C: BlockCipher>(
+    names: [&'static str; 7],
+    vectors: &ModesVectors,
+    cipher: impl Fn(&ModesVectors) -> Result<C, String>,
+) -> Vec<SelfTestCheck> {
+    let p = le_blocks(&vectors.p);
+    let [block_name, ecb_name, ctr_name, ofb_name, cbc_name, cfb_name, mac_name] = names;
+
+    let modes = || -> Result<CipherModes<C>, String> { Ok(CipherModes::new(cipher(vectors)?)) };
+
+    vec![
+        run_check(block_name, || {
+            let cipher = cipher(vectors)?;
+            let (plain, cipher_text) = (le_blocks(&[vectors.block.0]), le_blocks(&[vectors.block.1]));
+
+            let mut block = plain.clone();
+            cipher.encrypt_block(&mut block);
+            check("зашифрование", &block, &cipher_text)?;
+
+            cipher.decrypt_block(&mut block);
+            check("расшифрование", &block, &plain)
+        }),
+        run_check(ecb_name, || {
+            let modes = modes()?;
+            let c = modes.ecb_encrypt(&p, Padding::None)?.concat();
+
+            check("зашифрование", &c, &le_blocks(&vectors.ecb))?;
+            check("расшифрование", &modes.ecb_decrypt(&c, Padding::None)?, &p)
+        }),
+        run_check(ctr_name, || {
+            let modes = modes()?;
+            let (iv, s) = (le_blocks(&[vectors.ctr_iv]), C::BLOCK_SIZE * 8);
+            let c = modes.ctr_crypt(&p, s, &iv)?;
+
+            check("зашифрование", &c, &le_blocks(&vectors.ctr))?;
+            check("расшифрование", &modes.ctr_crypt(&c, s, &iv)?, &p)
+        }),
+        run_check(ofb_name, || {
+            let modes = modes()?;
+            let (iv, s) = (le_blocks(&[vectors.ofb_cfb_iv]), C::BLOCK_SIZE * 8);
+            let c = modes.ofb_crypt(&p, s, 2, &iv)?;
+
+            check("зашифрование", &c, &le_blocks(&vectors.ofb))?;
+            check("расшифрование", &modes.ofb_crypt(&c, s, 2, &iv)?, &p)
+        }),
+        run_check(cbc_name, || {
+            let modes = modes()?;
+            let iv = le_blocks(&[vectors.cbc_iv]);
+            let c = modes.cbc_encrypt(&p, vectors.cbc_z, &iv, Padding::None)?;
+
+            check("зашифрование", &c, &le_blocks(&vectors.cbc))?;
+            check("расшифрование", &modes.cbc_decrypt(&c, vectors.cbc_z, &iv, Padding::None)?, &p)
+        }),
+        run_check(cfb_name, || {
+            let modes = modes()?;
+            let (iv, s) = (le_blocks(&[vectors.ofb_cfb_iv]), C::BLOCK_SIZE * 8);
+            let c = modes.cfb_encrypt(&p, s, 2, &iv)?;
+
+            check("зашифрование", &c, &le_blocks(&vectors.cfb))?;
+            check("расшифрование", &modes.cfb_decrypt(&c, s, 2, &iv)?, &p)
+        }),
+        run_check(mac_name, || {
+            let cmac = CMAC::new(cipher(vectors)?)?;
+
+            check("имитовставка", &cmac.cmac(&p, vectors.mac_s)?, &le_blocks(&[vectors.mac]))
+        }),
+    ]
+}
+
+// Запуск одной проверки с перехватом паники
+fn run_check(name: &'static str, f: impl FnOnce() -> Result<(), String>) -> SelfTestCheck {
+    let result = match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(result) => result,
+        Err(_) => Err("аварийное завершение".to_string()),
+    };
+
+    SelfTestCheck { name, result }
+}
+
+fn check(what: &str, actual: &[u8], expected: &[u8]) -> Result<(), String> {
+    if actual != expected {
+        return Err(format!("{} не совпадает с контрольным примером", what));
+    }
+
+    Ok(())
+}
+
+/// Перевод блоков из big-endian записи стандарта в little-endian и склеивание
+pub fn le_blocks(blocks: &[&str]) -> Vec<u8> {
+    let mut res = vec![];
+
+    for block in blocks {
+        let mut bytes = hex_to_bytes(block);
+        bytes.reverse();
+        res.extend(bytes);
+    }
+
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self_test_passes() {
+        let report = self_test();
+
+        assert!(report.passed(), "{}", report);
+        assert_eq!(report.checks.len(), 16);
+        assert_eq!(report.failed().count(), 0);
+    }
+
+    #[test]
+    fn test_self_test_detects_mismatch() {
+        let check = run_check("Неверный пример", || {
+            check("зашифрование", &le_blocks(&["00ff"]), &le_blocks(&["ff00"]))
+        });
+        assert!(check.result.is_err());
+
+        // Паника внутри алгоритма не завершает самотестирование
+        let check = run_check("Паника", || panic!("S must be <= 128"));
+        assert!(check.result.is_err());
+
+        let report = SelfTestReport { checks: vec![check] };
+        assert!(!report.passed());
+        assert!(report.to_string().contains("Паника: ОШИБКА"));
+    }
+}
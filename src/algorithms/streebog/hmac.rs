@@ -0,0 +1,201 @@
+use zeroize::Zeroize;
+
+use super::{StreebogHasher, Write};
+
+// Длина блока Стрибога в байтах
+const BLOCK: usize = 64;
+
+/// HMAC_GOSTR3411_2012_256/512 (Р 50.1.113-2016): HMAC(K, T) = H((K ^ opad) || H((K ^ ipad) || T)),
+/// где H - Стрибог с хэш-кодом длины BITS бит, ipad = 0x36..36 и opad = 0x5c..5c длины 512 бит.
+/// Ключ короче 512 бит дополняется нулями, длиннее - заменяется своим хэш-кодом. Ключ, сообщение
+/// и имитовставка - строки байтов в порядке передачи. Сообщение можно подавать частями через update
+/// или std::io::Write.
+#[derive(Clone)]
+pub struct HmacStreebog<const BITS: u16> {
+    inner: StreebogHasher<BITS>, // H после (K ^ ipad)
+    outer: StreebogHasher<BITS>, // H после (K ^ opad)
+}
+
+/// HMAC_GOSTR3411_2012_256
+pub type HmacStreebog256 = HmacStreebog<256>;
+
+/// HMAC_GOSTR3411_2012_512
+pub type HmacStreebog512 = HmacStreebog<512>;
+
+#[allow(dead_code)]
+impl<const BITS: u16> HmacStreebog<BITS> {
+    pub fn new(key: &[u8]) -> Self {
+        let mut padded_key = [0u8; BLOCK];
+
+        if key.len() > BLOCK {
+            let mut hasher = StreebogHasher::<BITS>::new();
+            hasher.update(key);
+            let mut hash = hasher.finalize();
+            padded_key[..hash.len()].copy_from_slice(&hash);
+            hash.zeroize();
+        } else {
+            padded_key[..key.len()].copy_from_slice(key);
+        }
+
+        let mut inner = StreebogHasher::new();
+        let mut outer = StreebogHasher::new();
+
+        let mut pad = padded_key.map(|byte| byte ^ 0x36);
+        inner.update(&pad);
+        pad = padded_key.map(|byte| byte ^ 0x5c);
+        outer.update(&pad);
+
+        pad.zeroize();
+        padded_key.zeroize();
+
+        Self { inner, outer }
+    }
+
+    /// Добавление очередной части сообщения
+    pub fn update(&mut self, data: &[u8]) {
+        self.inner.update(data);
+    }
+
+    /// Имитовставка длины BITS бит
+    pub fn finalize(self) -> Vec<u8> {
+        let Self { inner, mut outer } = self;
+
+        let mut inner_hash = inner.finalize();
+        outer.update(&inner_hash);
+        inner_hash.zeroize();
+
+        outer.finalize()
+    }
+
+    /// Проверка имитовставки tag длины BITS бит. Имитовставки сравниваются без раннего выхода,
+    /// время не зависит от числа совпавших байтов.
+    pub fn verify(self, tag: &[u8]) -> bool {
+        let mut expected = self.finalize();
+        let diff = expected.iter().zip(tag).fold(0u8, |acc, (a, b)| acc | (a ^ b));
+        let valid = diff == 0 && tag.len() == expected.len();
+
+        expected.zeroize();
+
+        valid
+    }
+}
+
+impl<const BITS: u16> Write for HmacStreebog<BITS> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// HMAC по ключу key для message с длиной имитовставки bit_length (256 или 512 бит)
+pub fn hmac(key: &[u8], message: &[u8], bit_length: u16) -> Result<Vec<u8>, String> {
+    match bit_length {
+        256 => {
+            let mut hmac = HmacStreebog256::new(key);
+            hmac.update(message);
+            Ok(hmac.finalize())
+        }
+        512 => {
+            let mut hmac = HmacStreebog512::new(key);
+            hmac.update(message);
+            Ok(hmac.finalize())
+        }
+        _ => Err("Длина имитовставки HMAC должна быть 256 или 512 бит".to_string()),
+    }
+}
+
+#[allow(dead_code)]
+/// Проверка имитовставки tag для message за постоянное время. Длина HMAC определяется длиной tag
+/// (32 или 64 байта).
+pub fn hmac_verify(key: &[u8], message: &[u8], tag: &[u8]) -> Result<bool, String> {
+    match tag.len() {
+        32 => {
+            let mut hmac = HmacStreebog256::new(key);
+            hmac.update(message);
+            Ok(hmac.verify(tag))
+        }
+        64 => {
+            let mut hmac = HmacStreebog512::new(key);
+            hmac.update(message);
+            Ok(hmac.verify(tag))
+        }
+        _ => Err("Длина имитовставки HMAC должна быть 32 или 64 байта".to_string()),
+    }
+}
+
+/// Обертка над hmac для GUI: ключ в hex, сообщение - строка. Имитовставка в hex
+pub fn hmac_string(key_hex: &str, data: String, bit_length: u16) -> Result<String, String> {
+    let mut key = match hex::decode(key_hex.trim()) {
+        Ok(key) => key,
+        Err(_) => return Err("Ключ HMAC должен быть записан в hex".to_string()),
+    };
+
+    let res = hmac(&key, data.as_bytes(), bit_length);
+    key.zeroize();
+
+    Ok(hex::encode(res?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::algorithms::hex_to_bytes;
+
+    // Р 50.1.113-2016, примеры 4.1.1 и 4.1.2
+    const KEY: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+    const MESSAGE: &str = "0126bdb87800af214341456563780100";
+
+    #[test]
+    fn test_hmac_gost() {
+        let key = hex_to_bytes(KEY);
+        let message = hex_to_bytes(MESSAGE);
+
+        assert_eq!(
+            hmac(&key, &message, 256).unwrap(),
+            hex_to_bytes("a1aa5f7de402d7b3d323f2991c8d4534013137010a83754fd0af6d7cd4922ed9")
+        );
+        assert_eq!(
+            hmac(&key, &message, 512).unwrap(),
+            hex_to_bytes(concat!(
+                "a59bab22ecae19c65fbde6e5f4e9f5d8549d31f037f9df9b905500e171923a77",
+                "3d5f1530f2ed7e964cb2eedc29e9ad2f3afe93b2814f79f5000ffc0366c251e6"
+            ))
+        );
+        assert!(hmac(&key, &message, 128).is_err());
+    }
+
+    #[test]
+    fn test_hmac_verify_and_incremental() {
+        let key = hex_to_bytes(KEY);
+        let message = hex_to_bytes(MESSAGE);
+
+        for bit_length in [256, 512] {
+            let mut tag = hmac(&key, &message, bit_length).unwrap();
+            assert!(hmac_verify(&key, &message, &tag).unwrap());
+            assert!(!hmac_verify(&key[1..], &message, &tag).unwrap());
+            assert!(!hmac_verify(&key, &message[1..], &tag).unwrap());
+
+            tag[0] ^= 1;
+            assert!(!hmac_verify(&key, &message, &tag).unwrap());
+            assert!(hmac_verify(&key, &message, &tag[1..]).is_err());
+        }
+
+        // Сообщение частями и длинный ключ
+        let long_key = [0xAA; 100];
+        let mut incremental = HmacStreebog512::new(&long_key);
+        incremental.update(&message[..5]);
+        incremental.update(&message[5..]);
+        assert_eq!(incremental.finalize(), hmac(&long_key, &message, 512).unwrap());
+        assert_ne!(hmac(&long_key, &message, 256).unwrap(), hmac(&long_key[..64], &message, 256).unwrap());
+
+        assert_eq!(
+            hmac_string(KEY, "текст".to_string(), 256).unwrap(),
+            hex::encode(hmac(&key, "текст".as_bytes(), 256).unwrap())
+        );
+        assert!(hmac_string("0g", String::new(), 256).is_err());
+    }
+}
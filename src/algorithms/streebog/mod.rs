@@ -1,7 +1,11 @@
 use hex;
+use std::io::{self, Write};
+use std::sync::OnceLock;
+use zeroize::Zeroize;
 use crate::algorithms::{sum_mod2, sum_mod2_wo}; //hex_to_bytes};
 
 pub mod consts;
+pub mod hmac;
 
 // Получить мощность сообщения в формате [u8; 64]
 fn power_to_u64(rem: u128) -> [u8; 64] {
@@ -31,27 +35,47 @@ fn mul_matrice(b: [u8; 8]) -> [u8; 8] {
     out
 }
 
-// Последовательность операций, выполняемых в порядке: s, p, l
+/// Предвычисленная таблица преобразования LPS. Так как L линейно, слово i результата
+/// (байты 8i..8i+8) равно XOR по j = 0..7 строк lps[j][v[T[8i + j]]], где lps[j][b] -
+/// результат L для слова, в котором байт j равен P[b], а остальные нулевые.
+/// Слова хранятся как u64 в little-endian.
+struct Tables {
+    lps: [[u64; 256]; 8],
+}
+
+static TABLES: OnceLock<Box<Tables>> = OnceLock::new();
+
+// Таблица строится один раз при первом хэшировании
+fn tables() -> &'static Tables {
+    TABLES.get_or_init(|| {
+        let mut tables = Box::new(Tables { lps: [[0; 256]; 8] });
+
+        for pos in 0..8 {
+            for byte in 0..256 {
+                let mut a: [u8; 8] = [0; 8];
+                a[pos] = consts::P[byte];
+
+                tables.lps[pos][byte] = u64::from_le_bytes(mul_matrice(a));
+            }
+        }
+
+        tables
+    })
+}
+
+// Последовательность операций, выполняемых в порядке: s, p, l (через таблицу)
 fn lps(v: [u8; 64]) -> [u8; 64] {
+    let table = &tables().lps;
     let mut res: [u8; 64] = [0; 64];
 
-    //S - Добавить тест
-    for index in 0..64 as usize {
-        res[index] = consts::P[v[index] as usize];
-    }
+    for (word, out) in res.chunks_exact_mut(8).enumerate() {
+        let mut acc: u64 = 0;
 
-    let src = res;
-    //P - Добавить тест
-    for index in 0..64 as usize {
-        res[index] = src[consts::T[index] as usize];
-    }
+        for (pos, row) in table.iter().enumerate() {
+            acc ^= row[v[consts::T[8 * word + pos] as usize] as usize];
+        }
 
-    //L - Добавить тест
-    for index in 0..8 as usize {
-        let offset = 8 * index;
-        let slice: [u8; 8] = res[offset..(offset + 8)].try_into().unwrap();
-        // По 8 байт обработка
-        res[offset..(offset + 8)].copy_from_slice(&mul_matrice(slice));
+        out.copy_from_slice(&acc.to_le_bytes());
     }
 
     res
@@ -92,85 +116,137 @@ fn gn(h: &[u8; 64], m: &[u8; 64], n: &[u8; 64]) -> [u8; 64] {
     x
 }
 
-/// Хэширование Стрибог. Обеспечивает получение хэш-кода по message произвольной
-/// длины, представляемой срезом байтов. Длина хэш-кода задается параметром bit_length,
-/// где допустимыми являются значения 256/512. При некорректном значении вернет строковую
-/// ошибку. При корректной работе возвращает вектор байтов хэш-кода.
-pub fn streebog(message: &[u8], bit_length: u16) -> Result<Vec<u8>, String> {
-    if bit_length != 256 && bit_length != 512 {
-        return Err("Bit length must be 256 or 512\n".to_string());
-    }
-
-    // Этап 1: Присваивание начальных значений
-    let mut h: [u8; 64];
-
-    // 00000000 для 512 и 00000001 для 256
-    if bit_length == 256 {
-        h = [1; 64];
-    } else {
-        h = [0; 64];
-    }
-
-    let mut n: [u8; 64] = [0; 64];
-    let mut sigma: [u8; 64] = [0; 64];
-    let mut m: [u8; 64] = [0; 64];
+/// Инкрементальное хэширование Стрибог с длиной хэш-кода BITS (256 или 512 бит, см. Streebog256
+/// и Streebog512). Между вызовами update хранятся h, N, Sigma и неполный блок сообщения, поэтому
+/// сообщение можно подавать частями: finalize возвращает тот же хэш-код, что и streebog для всего
+/// сообщения. Реализует std::io::Write для использования с io::copy.
+#[derive(Clone)]
+pub struct StreebogHasher<const BITS: u16> {
+    h: [u8; 64],
+    n: [u8; 64],
+    sigma: [u8; 64],
+    block: [u8; 64], // Необработанная часть сообщения
+    block_len: usize,
+}
 
-    let mut count512 = 0; // Шаг по исходному сообщение, если оно >= 512
+/// Стрибог с хэш-кодом длины 256 бит
+pub type Streebog256 = StreebogHasher<256>;
 
-    // Этап 2: Проверка длины сообщения
-    while (message.len() - count512 * 64) * 8 >= 512 {
-        // Значение 512 в формате [u8; 64]
-        let mut t512: [u8; 64] = [0; 64];
-        t512[1] = 2u8;
+/// Стрибог с хэш-кодом длины 512 бит
+pub type Streebog512 = StreebogHasher<512>;
 
-        // Шаг 2.2: получение подвектора длины 512
-        m.copy_from_slice(&message[count512 * 64..(count512 * 64 + 64)]);
+#[allow(dead_code)]
+impl<const BITS: u16> StreebogHasher<BITS> {
+    pub fn new() -> Self {
+        // Этап 1: Присваивание начальных значений, 00000000 для 512 и 00000001 для 256
+        let h = if BITS == 256 { [1; 64] } else { [0; 64] };
 
-        h = gn(&h, &m, &n); // Шаг 2.3: h := gn(h, m);
-        n = sum_mod2_wo(&n, &t512); // Шаг 2.4: N := Vec512(lnt512(N) sum_mod2 512);
-        sigma = sum_mod2_wo(&sigma, &m); // Шаг 2.5: sigma := Vec512(lnt512(sigma) sum_mod2 Int512(m));
+        Self {
+            h,
+            n: [0; 64],
+            sigma: [0; 64],
+            block: [0; 64],
+            block_len: 0,
+        }
+    }
 
-        count512 += 1;
+    /// Добавление очередной части сообщения
+    pub fn update(&mut self, mut data: &[u8]) {
+        // Этап 2: каждый полный подвектор длины 512 обрабатывается сразу
+        while !data.is_empty() {
+            let take = (64 - self.block_len).min(data.len());
+            self.block[self.block_len..self.block_len + take].copy_from_slice(&data[..take]);
+            self.block_len += take;
+            data = &data[take..];
+
+            if self.block_len == 64 {
+                // Значение 512 в формате [u8; 64]
+                let mut t512: [u8; 64] = [0; 64];
+                t512[1] = 2u8;
+
+                self.h = gn(&self.h, &self.block, &self.n); // Шаг 2.3: h := gn(h, m);
+                self.n = sum_mod2_wo(&self.n, &t512); // Шаг 2.4: N := Vec512(lnt512(N) sum_mod2 512);
+                self.sigma = sum_mod2_wo(&self.sigma, &self.block); // Шаг 2.5: sigma := Vec512(lnt512(sigma) sum_mod2 Int512(m));
+
+                self.block_len = 0;
+            }
+        }
     }
 
-    // Этап 3: Итерационные вычисление хэш-кода
-    // Шаг 3.1: Дополнение нулями
-    m = [0; 64];
-    m[..(message.len() - count512 * 64)].copy_from_slice(&message[count512 * 64..]);
-    m[message.len() - count512 * 64] = 1u8;
+    /// Завершение хэширования: хэш-код длины BITS бит
+    pub fn finalize(mut self) -> Vec<u8> {
+        // Этап 3: Итерационные вычисление хэш-кода
+        // Шаг 3.1: Дополнение нулями
+        let mut m: [u8; 64] = [0; 64];
+        m[..self.block_len].copy_from_slice(&self.block[..self.block_len]);
+        m[self.block_len] = 1u8;
 
-    h = gn(&h, &m, &n); // Шаг 3.2 h := gn(h, m);
+        self.h = gn(&self.h, &m, &self.n); // Шаг 3.2 h := gn(h, m);
 
-    // Мощность сообщения M
-    let m_len = 8 * (message.len() - count512 * 64) as u128;
+        // Шаг 3.3 N := Vec512(lnt512(N) sum_mod2 |M|);
+        self.n = sum_mod2_wo(&self.n, &power_to_u64(8 * self.block_len as u128));
 
-    // Шаг 3.3 N := Vec512(lnt512(N) sum_mod2 |M|);
-    n = sum_mod2_wo(&n, &power_to_u64(m_len));
+        self.sigma = sum_mod2_wo(&self.sigma, &m); // Шаг 3.4 Sigma := Vec512(lnt512(Sigma) sum_mod2 lnt512(m));
+        self.h = gn(&self.h, &self.n, &[0; 64]); // Шаг 3.5 h := g0(h, N);
 
-    sigma = sum_mod2_wo(&sigma, &m); // Шаг 3.4 Sigma := Vec512(lnt512(Sigma) sum_mod2 lnt512(m));
-    h = gn(&h, &n, &[0; 64]); // Шаг 3.5 h := g0(h, N);
+        // Шаг 3.6: Выбор длины хэша
+        self.h = gn(&self.h, &self.sigma, &[0; 64]);
 
-    // print!("N = ");
-    // print_bytes(&n);
-    // println!();
-    // print!("Sigma = ");
-    // print_bytes(&sigma);
-    // println!();
-    // print!("h = ");
-    // print_bytes(&h);
-    // println!();
+        // Шаг 3.7: Возврат хэша
+        if BITS == 256 {
+            self.h[32..].to_vec()
+        } else {
+            self.h.to_vec()
+        }
+    }
+}
 
-    // Шаг 3.6: Выбор длины хэша
-    h = gn(&h, &sigma, &[0; 64]);
+impl<const BITS: u16> Default for StreebogHasher<BITS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
-    if bit_length == 256 {
-        return Ok(h[32..].to_vec());
+// Состояние затирается: в HMAC оно выработано из ключа
+impl<const BITS: u16> Drop for StreebogHasher<BITS> {
+    fn drop(&mut self) {
+        self.h.zeroize();
+        self.n.zeroize();
+        self.sigma.zeroize();
+        self.block.zeroize();
     }
+}
 
-    // Шаг 3.7: Возврат хэша
-    return Ok(h.to_vec());
+impl<const BITS: u16> Write for StreebogHasher<BITS> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
 }
 
+/// Хэширование Стрибог. Обеспечивает получение хэш-кода по message произвольной
+/// длины, представляемой срезом байтов. Длина хэш-кода задается параметром bit_length,
+/// где допустимыми являются значения 256/512. При некорректном значении вернет строковую
+/// ошибку. При корректной работе возвращает вектор байтов хэш-кода.
+pub fn streebog(message: &[u8], bit_length: u16) -> Result<Vec<u8>, String> {
+    match bit_length {
+        256 => {
+            let mut hasher = Streebog256::new();
+            hasher.update(message);
+            Ok(hasher.finalize())
+        }
+        512 => {
+            let mut hasher = Streebog512::new();
+            hasher.update(message);
+            Ok(hasher.finalize())
+        }
+        _ => Err("Bit length must be 256 or 512\n".to_string()),
+    }
+}
 
 /// Обертка над streebog, принимающая Вектор байтов
 pub fn streebog_string(data: String, bit_length: u16) -> Result<String, String> {
@@ -192,15 +268,19 @@ pub fn streebog_string(data: String, bit_length: u16) -> Result<String, String>
 mod tests {
     // Note this useful idiom: importing names from outer (for mod tests) scope.
     use super::*;
-    use crate::algorithms::hex_to_bytes;
+    use crate::algorithms::self_test::{le_blocks, STREEBOG_VECTORS};
+
+    // ГОСТ Р 34.11-2018 А.1 (M1) и А.2 (M2): сообщение и хэш-код длины bit_length в little-endian
+    fn gost_example(idx: usize, bit_length: usize) -> (Vec<u8>, Vec<u8>) {
+        let (_, message, hash512, hash256) = STREEBOG_VECTORS[idx];
+        let hash = if bit_length == 512 { hash512 } else { hash256 };
+
+        (le_blocks(&[message]), le_blocks(&[hash]))
+    }
 
     #[test]
     fn test_streebog512_message_less_512() -> Result<(), String> {
-        let mut hash_true: Vec<u8> = hex_to_bytes("486f64c1917879417fef082b3381a4e211c324f074654c38823a7b76f830ad00fa1fbae42b1285c0352f227524bc9ab16254288dd6863dccd5b9f54a1ad0541b");
-        let mut message = hex_to_bytes("323130393837363534333231303938373635343332313039383736353433323130393837363534333231303938373635343332313039383736353433323130");
-
-        hash_true.reverse();
-        message.reverse();
+        let (message, hash_true) = gost_example(0, 512);
 
         let hash: Vec<u8> = streebog(&message, 512)?;
 
@@ -211,11 +291,7 @@ mod tests {
 
     #[test]
     fn test_streebog512_message_greater_512() -> Result<(), String> {
-        let mut hash_true: Vec<u8> = hex_to_bytes("28fbc9bada033b1460642bdcddb90c3fb3e56c497ccd0f62b8a2ad4935e85f037613966de4ee00531ae60f3b5a47f8dae06915d5f2f194996fcabf2622e6881e");
-        let mut message: Vec<u8> = hex_to_bytes("fbe2e5f0eee3c820fbeafaebef20fffbf0e1e0f0f520e0ed20e8ece0ebe5f0f2f120fff0eeec20f120faf2fee5e2202ce8f6f3ede220e8e6eee1e8f0f2d1202ce8f0f2e5e220e5d1");
-
-        hash_true.reverse();
-        message.reverse();
+        let (message, hash_true) = gost_example(1, 512);
 
         let hash: Vec<u8> = streebog(&message, 512)?;
 
@@ -226,12 +302,7 @@ mod tests {
 
     #[test]
     fn test_streebog256_message_less_512() -> Result<(), String> {
-        let mut hash_true: Vec<u8> =
-            hex_to_bytes("00557be5e584fd52a449b16b0251d05d27f94ab76cbaa6da890b59d8ef1e159d");
-        let mut message: Vec<u8> = hex_to_bytes("323130393837363534333231303938373635343332313039383736353433323130393837363534333231303938373635343332313039383736353433323130");
-
-        hash_true.reverse();
-        message.reverse();
+        let (message, hash_true) = gost_example(0, 256);
 
         let hash: Vec<u8> = streebog(&message, 256)?;
         assert_eq!(hash_true, hash);
@@ -245,12 +316,7 @@ mod tests {
 
     #[test]
     fn test_streebog256_message_greater_512() -> Result<(), String> {
-        let mut hash_true: Vec<u8> =
-            hex_to_bytes("508f7e553c06501d749a66fc28c6cac0b005746d97537fa85d9e40904efed29d");
-        let mut message: Vec<u8> = hex_to_bytes("fbe2e5f0eee3c820fbeafaebef20fffbf0e1e0f0f520e0ed20e8ece0ebe5f0f2f120fff0eeec20f120faf2fee5e2202ce8f6f3ede220e8e6eee1e8f0f2d1202ce8f0f2e5e220e5d1");
-
-        hash_true.reverse();
-        message.reverse();
+        let (message, hash_true) = gost_example(1, 256);
 
         let hash: Vec<u8> = streebog(&message, 256)?;
 
@@ -258,4 +324,121 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_streebog_incremental() -> Result<(), String> {
+        use crate::algorithms::random_vec;
+
+        let message = random_vec(1000);
+
+        for bit_length in [256, 512] {
+            let hash = streebog(&message, bit_length)?;
+
+            // Разбиение на части разной длины, в том числе по границе блока
+            for step in [1, 7, 63, 64, 65, 200, 1000] {
+                let res = if bit_length == 256 {
+                    let mut hasher = Streebog256::new();
+                    message.chunks(step).for_each(|chunk| hasher.update(chunk));
+                    hasher.finalize()
+                } else {
+                    let mut hasher = Streebog512::new();
+                    message.chunks(step).for_each(|chunk| hasher.update(chunk));
+                    hasher.finalize()
+                };
+
+                assert_eq!(res, hash, "{} бит, шаг {}", bit_length, step);
+            }
+        }
+
+        // Пустое сообщение и сообщение ровно из одного блока
+        assert_eq!(Streebog512::new().finalize(), streebog(&[], 512)?);
+        let mut hasher = Streebog256::new();
+        hasher.update(&message[..64]);
+        assert_eq!(hasher.finalize(), streebog(&message[..64], 256)?);
+
+        // Запись через io::copy
+        let mut hasher = Streebog256::new();
+        io::copy(&mut &message[..], &mut hasher).map_err(|e| e.to_string())?;
+        assert_eq!(hasher.finalize(), streebog(&message, 256)?);
+
+        Ok(())
+    }
+
+    // Побитовое LPS без таблиц для сверки
+    fn lps_reference(v: [u8; 64]) -> [u8; 64] {
+        let mut res: [u8; 64] = [0; 64];
+
+        // S
+        for index in 0..64 {
+            res[index] = consts::P[v[index] as usize];
+        }
+
+        let src = res;
+        // P
+        for index in 0..64 {
+            res[index] = src[consts::T[index] as usize];
+        }
+
+        // L
+        for index in 0..8 {
+            let offset = 8 * index;
+            let slice: [u8; 8] = res[offset..(offset + 8)].try_into().unwrap();
+            // По 8 байт обработка
+            res[offset..(offset + 8)].copy_from_slice(&mul_matrice(slice));
+        }
+
+        res
+    }
+
+    #[test]
+    fn test_lps_table() {
+        use crate::algorithms::random_vec;
+
+        for _ in 0..100 {
+            let v: [u8; 64] = random_vec(64).try_into().unwrap();
+            assert_eq!(lps(v), lps_reference(v));
+        }
+
+        let mut v = [0u8; 64];
+        for idx in 0..64 {
+            v[idx] = 0xFF;
+            assert_eq!(lps(v), lps_reference(v), "байт {}", idx);
+            v[idx] = 0;
+        }
+    }
+
+    // Замер: cargo test --release test_lps_timings -- --ignored --nocapture
+    #[test]
+    #[ignore = "benchmark"]
+    fn test_lps_timings() {
+        use std::{hint::black_box, time::Instant};
+
+        const ROUNDS: usize = 1_000_000;
+
+        // Время ROUNDS преобразований, каждое следующее применяется к результату предыдущего
+        let measure = |transform: fn([u8; 64]) -> [u8; 64]| {
+            let mut v = [0x5Au8; 64];
+
+            let start = Instant::now();
+            for _ in 0..ROUNDS {
+                v = black_box(transform(v));
+            }
+
+            (start.elapsed(), v)
+        };
+
+        let (reference_time, reference_v) = measure(lps_reference);
+        let (table_time, table_v) = measure(lps);
+        assert_eq!(table_v, reference_v);
+
+        println!("LPS: {ROUNDS} преобразований, без таблиц {reference_time:?}, по таблице {table_time:?}");
+
+        // Хэш 1 МБ, в котором LPS занимает основную часть времени
+        let message = vec![0xA5u8; 1 << 20];
+        let start = Instant::now();
+        black_box(streebog(&message, 512).unwrap());
+        println!("Стрибог-512: 1 МБ за {:?}", start.elapsed());
+
+        assert!(table_time < reference_time);
+    }
 }
@@ -1,23 +1,34 @@
 use iced::{
     Length, Task, alignment::Horizontal, clipboard, 
-    widget::{button, center, column, combo_box, row, text, text_editor, tooltip}};
+    widget::{button, center, column, combo_box, progress_bar, row, text, text_editor, text_input, tooltip}};
+use iced::futures::channel::mpsc;
 use rfd;
 
 use rand;
-use std::{fmt::Write, str::from_utf8, fs, io::{BufRead, BufReader}, path::PathBuf};
+use std::{fmt::Write, str::from_utf8, fs, panic::{self, AssertUnwindSafe}, path::{Path, PathBuf}, sync::Arc};
 
-use crate::algorithms::{self, to_hex, hex_to_bytes};
+use crate::algorithms::{self, to_hex};
 use crate::algorithms::streebog::streebog_string;
-use crate::algorithms::kuznechik::Kuznechik;
-use crate::algorithms::block_cipher_modes;
+use crate::algorithms::streebog::hmac::hmac_string;
+use crate::algorithms::self_test::SelfTestReport;
+use crate::algorithms::key_file::KeyFileMeta;
+use crate::algorithms::secret::Secret;
+use crate::algorithms::kuznechik::{Kuznechik, KuznechikKey};
+use crate::algorithms::magma::{Magma, MagmaKey};
+use crate::algorithms::block_cipher_modes::{self, Padding};
+use crate::algorithms::ctr_cmac::CtrCmac;
+use crate::algorithms::key_wrap::KeyWrap;
 use crate::gui::{button_style_rsa, button_style_streebog, button_style_kuznechik, backward_button_style, 
-    text_editor_style_read, text_editor_style_write, combo_box_input_style, combo_box_menu_style};
+    text_editor_style_read, text_editor_style_write, combo_box_input_style, combo_box_menu_style, text_input_style};
 
 pub struct Cryptography {
     // General data
     login: String,
     state: Message,
 
+    // Результат самотестирования при запуске. Если он не пройден, операции запрещены
+    self_test: SelfTestReport,
+
     // Информация по завершению операций
     topbar_info: String,    
     compute_info: String,
@@ -29,24 +40,233 @@ pub struct Cryptography {
     // Streebog
     streebog_text: text_editor::Content,
     streebog_hash: text_editor::Content,
+    hmac_key: Secret<String>, // Ключ HMAC в hex (пустой - обычный хэш-код)
 
-    // Kuznechik
+    // Kuznechik, Magma
+    block_ciphers: combo_box::State<BlockCiphers>,
+    current_cipher: BlockCiphers,
     kuznechik_modes: combo_box::State<KuznechickModes>,
     current_mode: Option<KuznechickModes>,  
-    keys_kuznechik: Kuznechik,
-    mods_param: (u32, u32, Vec<u8>), // s(0 < s <= 128), z (целое от 1), IV - инициализирующий вектор
+    paddings: combo_box::State<Padding>,
+    current_padding: Padding, // Процедура дополнения для ECB и CBC
+    keys_kuznechik: Option<CipherKey>,
+    cipher_modes: Option<Arc<CipherModes>>, // Режимы и CMAC на keys_kuznechik, создаются при установке ключа
+    keys_password: Secret<String>, // Пароль для защиты файла с ключами (пустой - без защиты)
+    mods_param: (u32, u32, Vec<u8>), // s(0 < s <= 8n), z (целое от 1), IV - инициализирующий вектор длины n*z байт
+    ctr_progress: Option<(usize, usize)>, // Ход фонового CTR: обработано байт, всего байт
     kuzcnechik_text: text_editor::Content,
     keys_kuznechik_text: text_editor::Content
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BlockCiphers {
+    Kuznechik,
+    Magma
+}
+
+impl BlockCiphers {
+    // Длина блока шифра в байтах
+    fn block_size(&self) -> usize {
+        match self {
+            BlockCiphers::Kuznechik => 16,
+            BlockCiphers::Magma => 8
+        }
+    }
+}
+
+impl std::fmt::Display for BlockCiphers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            BlockCiphers::Kuznechik => "Кузнечик",
+            BlockCiphers::Magma => "Магма"
+        })
+    }
+}
+
+// Ключ выбранного блочного шифра
+enum CipherKey {
+    Kuznechik(KuznechikKey),
+    Magma(MagmaKey)
+}
+
+// Режимы работы выбранного блочного шифра и CMAC на том же ключе (в куче,
+// чтобы размеры вариантов с ключами Кузнечика и Магмы не расходились сильнее)
+enum CipherModes {
+    Kuznechik(block_cipher_modes::CipherModes<Kuznechik>, Box<block_cipher_modes::CMAC<Kuznechik>>),
+    Magma(block_cipher_modes::CipherModes<Magma>, Box<block_cipher_modes::CMAC<Magma>>)
+}
+
+impl CipherKey {
+    // Вспомогательные ключи CMAC вырабатываются здесь один раз
+    fn modes(&self) -> CipherModes {
+        const CMAC_BLOCK: &str = "CMAC определен для блоков Кузнечика и Магмы";
+
+        match self {
+            CipherKey::Kuznechik(key) => CipherModes::Kuznechik(
+                block_cipher_modes::CipherModes::new(Kuznechik::with_key(key.clone_secret())),
+                Box::new(block_cipher_modes::CMAC::new(Kuznechik::with_key(key.clone_secret())).expect(CMAC_BLOCK))
+            ),
+            CipherKey::Magma(key) => CipherModes::Magma(
+                block_cipher_modes::CipherModes::new(Magma::with_key(key.clone_secret())),
+                Box::new(block_cipher_modes::CMAC::new(Magma::with_key(key.clone_secret())).expect(CMAC_BLOCK))
+            )
+        }
+    }
+
+    // Экспорт ключа key на ключе экспорта self (KExp15) со случайным IV: IV (n/2 байт) || KEXP
+    fn wrap_key(&self, key: &CipherKey) -> Result<Vec<u8>, String> {
+        match (self, key) {
+            (CipherKey::Kuznechik(kek), CipherKey::Kuznechik(key)) => {
+                let iv = algorithms::random_vec(8);
+                let wrapped = KeyWrap::from_kek(Kuznechik::with_key(kek.clone_secret()))?.kexp15(key.master_key(), &iv)?;
+                Ok([iv, wrapped].concat())
+            },
+            (CipherKey::Magma(kek), CipherKey::Magma(key)) => {
+                let iv = algorithms::random_vec(4);
+                let wrapped = KeyWrap::from_kek(Magma::with_key(kek.clone_secret()))?.kexp15(key.master_key(), &iv)?;
+                Ok([iv, wrapped].concat())
+            },
+            _ => Err("Ключ экспорта и экспортируемый ключ должны относиться к одному шифру".to_string())
+        }
+    }
+
+    // Импорт ключа (KImp15) из IV || KEXP на ключе экспорта self
+    fn unwrap_key(&self, data: &[u8]) -> Result<CipherKey, String> {
+        match self {
+            CipherKey::Kuznechik(kek) => {
+                let (iv, wrapped) = data.split_at(data.len().min(8));
+                let key = KeyWrap::from_kek(Kuznechik::with_key(kek.clone_secret()))?.kimp15(wrapped, iv)?;
+                Ok(CipherKey::Kuznechik(KuznechikKey::new(key.expose())))
+            },
+            CipherKey::Magma(kek) => {
+                let (iv, wrapped) = data.split_at(data.len().min(4));
+                let key = KeyWrap::from_kek(Magma::with_key(kek.clone_secret()))?.kimp15(wrapped, iv)?;
+                Ok(CipherKey::Magma(MagmaKey::new(key.expose())))
+            }
+        }
+    }
+}
+
+impl CipherModes {
+    fn ecb_encrypt(&self, message: &[u8], padding: Padding) -> Result<Vec<u8>, String> {
+        match self {
+            CipherModes::Kuznechik(modes, _) => Ok(modes.ecb_encrypt(message, padding)?.concat()),
+            CipherModes::Magma(modes, _) => Ok(modes.ecb_encrypt(message, padding)?.concat())
+        }
+    }
+
+    fn ecb_decrypt(&self, message: &[u8], padding: Padding) -> Result<Vec<u8>, String> {
+        match self {
+            CipherModes::Kuznechik(modes, _) => modes.ecb_decrypt(message, padding),
+            CipherModes::Magma(modes, _) => modes.ecb_decrypt(message, padding)
+        }
+    }
+
+    fn cbc_encrypt(&self, message: &[u8], z: usize, iv: &[u8], padding: Padding) -> Result<Vec<u8>, String> {
+        match self {
+            CipherModes::Kuznechik(modes, _) => modes.cbc_encrypt(message, z, iv, padding),
+            CipherModes::Magma(modes, _) => modes.cbc_encrypt(message, z, iv, padding)
+        }
+    }
+
+    fn cbc_decrypt(&self, message: &[u8], z: usize, iv: &[u8], padding: Padding) -> Result<Vec<u8>, String> {
+        match self {
+            CipherModes::Kuznechik(modes, _) => modes.cbc_decrypt(message, z, iv, padding),
+            CipherModes::Magma(modes, _) => modes.cbc_decrypt(message, z, iv, padding)
+        }
+    }
+
+    // Многопоточный CTR с вызовом progress после каждого отрезка. IV для CTR - первые n/2 байт
+    // общего IV (более короткий IV отклоняется при проверке параметров)
+    fn ctr_crypt_progress(&self, message: &[u8], s: usize, iv: &[u8], progress: &(dyn Fn(usize, usize) + Sync)) -> Result<Vec<u8>, String> {
+        match self {
+            CipherModes::Kuznechik(modes, _) => modes.ctr_crypt_progress(message, s, iv.get(..8).unwrap_or(iv), progress),
+            CipherModes::Magma(modes, _) => modes.ctr_crypt_progress(message, s, iv.get(..4).unwrap_or(iv), progress)
+        }
+    }
+
+    fn ofb_crypt(&self, message: &[u8], s: usize, z: usize, iv: &[u8]) -> Result<Vec<u8>, String> {
+        match self {
+            CipherModes::Kuznechik(modes, _) => modes.ofb_crypt(message, s, z, iv),
+            CipherModes::Magma(modes, _) => modes.ofb_crypt(message, s, z, iv)
+        }
+    }
+
+    fn cfb_encrypt(&self, message: &[u8], s: usize, z: usize, iv: &[u8]) -> Result<Vec<u8>, String> {
+        match self {
+            CipherModes::Kuznechik(modes, _) => modes.cfb_encrypt(message, s, z, iv),
+            CipherModes::Magma(modes, _) => modes.cfb_encrypt(message, s, z, iv)
+        }
+    }
+
+    fn cfb_decrypt(&self, message: &[u8], s: usize, z: usize, iv: &[u8]) -> Result<Vec<u8>, String> {
+        match self {
+            CipherModes::Kuznechik(modes, _) => modes.cfb_decrypt(message, s, z, iv),
+            CipherModes::Magma(modes, _) => modes.cfb_decrypt(message, s, z, iv)
+        }
+    }
+
+    // IV для CTR-ACPKM - первые n/2 байт общего IV
+    fn ctr_acpkm_crypt(&self, message: &[u8], iv: &[u8]) -> Result<Vec<u8>, String> {
+        match self {
+            CipherModes::Kuznechik(modes, _) => modes.ctr_acpkm_crypt(message, ACPKM_SECTION, iv.get(..8).unwrap_or(iv)),
+            CipherModes::Magma(modes, _) => modes.ctr_acpkm_crypt(message, ACPKM_SECTION, iv.get(..4).unwrap_or(iv))
+        }
+    }
+
+    // Имитовставка длины n бит, ключ ACPKM-Master меняется каждые 2(k + n) бит
+    fn omac_acpkm(&self, message: &[u8]) -> Result<Vec<u8>, String> {
+        match self {
+            CipherModes::Kuznechik(_, cmac) => cmac.omac_acpkm(message, ACPKM_SECTION, 2 * (32 + 16), 128),
+            CipherModes::Magma(_, cmac) => cmac.omac_acpkm(message, ACPKM_SECTION, 2 * (32 + 8), 64)
+        }
+    }
+
+    // Аутентифицированное шифрование CTR + CMAC со случайным IV: IV || C || T
+    fn ctr_cmac_encrypt(&self, message: &[u8]) -> Result<Vec<u8>, String> {
+        match self {
+            CipherModes::Kuznechik(modes, _) => CtrCmac::new(modes.keys.clone_secret())?.encrypt(message),
+            CipherModes::Magma(modes, _) => CtrCmac::new(modes.keys.clone_secret())?.encrypt(message)
+        }
+    }
+
+    fn ctr_cmac_decrypt(&self, data: &[u8]) -> Result<Vec<u8>, String> {
+        match self {
+            CipherModes::Kuznechik(modes, _) => CtrCmac::new(modes.keys.clone_secret())?.decrypt(data),
+            CipherModes::Magma(modes, _) => CtrCmac::new(modes.keys.clone_secret())?.decrypt(data)
+        }
+    }
+
+    // Имитовставка CMAC длины n бит на загруженном ключе
+    fn cmac(&self, message: &[u8]) -> Result<Vec<u8>, String> {
+        match self {
+            CipherModes::Kuznechik(_, cmac) => cmac.cmac(message, 128),
+            CipherModes::Magma(_, cmac) => cmac.cmac(message, 64)
+        }
+    }
+
+    fn cmac_verify(&self, message: &[u8], tag: &[u8]) -> Result<bool, String> {
+        match self {
+            CipherModes::Kuznechik(_, cmac) => cmac.verify(message, tag),
+            CipherModes::Magma(_, cmac) => cmac.verify(message, tag)
+        }
+    }
+}
+
+// Длина секции ACPKM в байтах (целое число блоков для обоих шифров)
+const ACPKM_SECTION: usize = 4096;
+
 #[derive(Debug, Clone)]
 pub enum KuznechickModes {
     ECB,
     CTR,
     OFB,
     CBC,
-    CFB
-    //MAC
+    CFB,
+    CtrAcpkm,
+    OmacAcpkm,
+    Mac,
+    CtrCmac
 }
 
 impl std::fmt::Display for KuznechickModes {
@@ -56,12 +276,34 @@ impl std::fmt::Display for KuznechickModes {
             KuznechickModes::CTR => "CTR (Режим гаммирования)",
             KuznechickModes::OFB => "OFB (Режим гаммирования с обратной связью по выходу)",
             KuznechickModes::CBC => "CBC (Режим простой замены с зацеплением)",
-            KuznechickModes::CFB => "CFB (Режим гаммирования с обратной связью по шифротексту)" 
-            //KuznechickModes::MAC => "MAC",
+            KuznechickModes::CFB => "CFB (Режим гаммирования с обратной связью по шифротексту)",
+            KuznechickModes::CtrAcpkm => "CTR-ACPKM (Режим гаммирования со сменой ключа)",
+            KuznechickModes::OmacAcpkm => "OMAC-ACPKM (Имитовставка со сменой ключа)",
+            KuznechickModes::Mac => "MAC (Имитовставка)",
+            KuznechickModes::CtrCmac => "CTR + CMAC (Аутентифицированное шифрование)"
+        })
+    }
+}
+
+impl std::fmt::Display for Padding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Padding::None => "Без дополнения",
+            Padding::Procedure1 => "Дополнение: процедура 1",
+            Padding::Procedure2 => "Дополнение: процедура 2",
+            Padding::Procedure3 => "Дополнение: процедура 3"
         })
     }
 }
 
+// Сообщения фонового потока CTR
+#[derive(Debug, Clone)]
+pub enum CtrTask {
+    Progress(usize, usize), // Обработано байт, всего байт
+    Saved(PathBuf),         // Зашифрованные данные записаны в файл
+    Decrypted(String),      // Расшифрованный текст
+    Failed(String)
+}
 
 #[derive(Debug, Clone)]
 pub enum Message {
@@ -73,19 +315,28 @@ pub enum Message {
     // Все состояния, связанные со Стрибогом
     Streebog,
     StreebogCompute,
+    HmacKeyInput(String),
 
-    // Все состояния, связанные с Кузнечиком
+    // Все состояния, связанные с Кузнечиком и Магмой
+    BlockCipherChange(BlockCiphers),
     KuznechickChangeMode(KuznechickModes),
+    PaddingChange(Padding),
     KuznechickSaveFile(String),
 
     KuznechickKeys,
     KuznechickKeysGenerate,
     KuznechickKeysLoad,
     KuznechickKeysSave,
+    KuznechickKeysWrap,
+    KuznechickKeysUnwrap,
+    KeysPasswordInput(String),
 
     KuznechickEncryption,
     KuznechickEncryptionCompute,
     KuznechickDecryptionCompute,
+    MacFileCompute,
+    MacFileVerify,
+    CtrTaskUpdate(CtrTask),
 
     // Все состояния, связанные с побочными операциями
     InputTextEditor(text_editor::Action),
@@ -96,12 +347,13 @@ pub enum Message {
 const CUSTOM_FONT: iced::Font = iced::Font::with_name("crypto-icons");
 
 impl Cryptography {
-    pub fn new(login: String) -> Self
+    pub fn new(login: String, self_test: SelfTestReport) -> Self
     {
         Self
         {
             login, 
             state: Message::Select,
+            self_test,
             topbar_info: String::new(),
             compute_info: String::new(),
             topbar_error: String::new(),
@@ -109,18 +361,37 @@ impl Cryptography {
 
             streebog_text: text_editor::Content::new(), 
             streebog_hash: text_editor::Content::new(),
+            hmac_key: Secret::new(String::new()),
 
-            keys_kuznechik: Kuznechik { keys: (Vec::new(), vec![[0u8; 16]; 10]) },
+            block_ciphers: combo_box::State::new(vec![
+                BlockCiphers::Kuznechik,
+                BlockCiphers::Magma
+            ]),
+            current_cipher: BlockCiphers::Kuznechik,
+            keys_kuznechik: None,
+            cipher_modes: None,
+            keys_password: Secret::new(String::new()),
             kuznechik_modes: combo_box::State::new(vec![
                 KuznechickModes::ECB,
                 KuznechickModes::CTR,
                 KuznechickModes::OFB,
                 KuznechickModes::CBC,
-                KuznechickModes::CFB
-                //KuznechickModes::MAC
+                KuznechickModes::CFB,
+                KuznechickModes::CtrAcpkm,
+                KuznechickModes::OmacAcpkm,
+                KuznechickModes::Mac,
+                KuznechickModes::CtrCmac
             ]),
             mods_param: (0, 0, Vec::new()),
             current_mode: None,
+            paddings: combo_box::State::new(vec![
+                Padding::None,
+                Padding::Procedure1,
+                Padding::Procedure2,
+                Padding::Procedure3
+            ]),
+            current_padding: Padding::default(),
+            ctr_progress: None,
             kuzcnechik_text: text_editor::Content::new(),
             keys_kuznechik_text: text_editor::Content::new()
         }
@@ -138,17 +409,34 @@ impl Cryptography {
     // Перевод ключей в строку для отображения в GUI
     fn keys_to_string(&self) -> Result<String, String>
     {
+        let keys = match &self.keys_kuznechik {
+            Some(keys) => keys,
+            None => {return Err("Ключи не были созданы".to_string());}
+        };
+
+        // Ключ K и итерационные ключи в hex
+        let (master, round_keys): (String, Vec<String>) = match keys {
+            CipherKey::Kuznechik(keys) => (
+                to_hex(keys.master_key()),
+                keys.round_keys().iter().map(|key| to_hex(key)).collect()
+            ),
+            CipherKey::Magma(keys) => (
+                to_hex(keys.master_key()),
+                keys.round_keys().iter().map(|key| to_hex(&key.to_le_bytes())).collect()
+            )
+        };
+
         // Формирование String из ключей
         let mut text:String = String::new();
 
-        match writeln!(text, "K = {}", to_hex(&self.keys_kuznechik.keys.0)) {
+        match writeln!(text, "K = {}", master) {
             Ok(_) => {},
             Err(_) => {return Err("Ошибка отображения ключей в интерфейсе".to_string());}
         };
 
         // Запись всех итерационных ключей в файл
-        for idx in 0..self.keys_kuznechik.keys.1.len() {
-            match writeln!(text, "K{} = {}", idx + 1, to_hex(&self.keys_kuznechik.keys.1[idx])) {
+        for (idx, key) in round_keys.iter().enumerate() {
+            match writeln!(text, "K{} = {}", idx + 1, key) {
                 Ok(_) => {},
                 Err(_) => {return Err("Ошибка отображения ключей в интерфейсе".to_string());}
             };
@@ -163,83 +451,149 @@ impl Cryptography {
         return Ok(text);
     }
     
-    fn get_keys_from_file(&self, path: &PathBuf) -> Result<(Vec<u8>, Vec<[u8; 16]>, u32, u32, Vec<u8>), String> {
-        let file = match fs::File::open(path)
-        {
-            Ok(file) => file,
-            Err(_) => {return Err("Ошибка открытия файла с ключами".to_string());}
-        };
+    // Установка ключа выбранного шифра вместе с режимами и CMAC на нем
+    fn set_keys(&mut self, keys: Option<CipherKey>) {
+        self.cipher_modes = keys.as_ref().map(|keys| Arc::new(keys.modes()));
+        self.keys_kuznechik = keys;
+    }
 
-        let reader = BufReader::new(file);
+    // Пароль от файла с ключами, если он введен
+    fn keys_password(&self) -> Option<&str> {
+        Some(self.keys_password.expose().as_str()).filter(|password| !password.is_empty())
+    }
 
-        let mut main_key: Vec<u8> = Vec::new();
-        let mut round_keys: Vec<[u8; 16]> = Vec::new();
-        let mut s = 1;
-        let mut z = 1;
-        let mut iv = Vec::new();
+    // Загрузка ключа выбранного шифра: версия, контрольная сумма, параметры и пароль проверяются при загрузке
+    fn get_keys_from_file(&self, path: &Path) -> Result<(CipherKey, KeyFileMeta), String> {
+        let password = self.keys_password();
 
-        for line in reader.lines() {
-            let line = match line 
-            {
-                Ok(line) => line,
-                Err(_) => {return Err(" Некорректный файл с ключами".to_string());}
-            };
+        Ok(match self.current_cipher {
+            BlockCiphers::Kuznechik => {
+                let (key, meta) = KuznechikKey::from_file(path, password)?;
+                (CipherKey::Kuznechik(key), meta)
+            },
+            BlockCiphers::Magma => {
+                let (key, meta) = MagmaKey::from_file(path, password)?;
+                (CipherKey::Magma(key), meta)
+            }
+        })
+    }
 
-            let parts: Vec<&str> = line.split('=').map(|s| s.trim()).collect();
+    // Запись мастер-ключа, логина владельца и параметров режимов в файл ключа
+    fn save_keys_into_file(&self, path: &Path) -> Result<PathBuf, String> {
+        let meta = KeyFileMeta::new(&self.login, self.mods_param.0, self.mods_param.1, &self.mods_param.2);
 
-            if parts.len() != 2
-            {
-                return Err(" Некорректный файл с ключами".to_string());
-            }
+        match &self.keys_kuznechik {
+            Some(CipherKey::Kuznechik(key)) => key.save_to_file(path, &meta, self.keys_password()),
+            Some(CipherKey::Magma(key)) => key.save_to_file(path, &meta, self.keys_password()),
+            None => Err("Ключи не были созданы".to_string())
+        }
+    }
 
-            let name = parts[0];
-            let value = parts[1];
-            let mut bytes;
+    // Запуск CTR над data в отдельном потоке, чтобы не блокировать интерфейс. Результат записывается
+    // в файл output, а без него расшифрованный текст помещается в редактор
+    fn ctr_start(&mut self, cipher: Arc<CipherModes>, data: Vec<u8>, output: Option<PathBuf>) -> Task<Message>
+    {
+        let (sender, receiver) = mpsc::unbounded();
+        let s = self.mods_param.0 as usize;
+        let iv = self.mods_param.2.clone();
+
+        self.ctr_progress = Some((0, data.len()));
+
+        std::thread::spawn(move || {
+            // Паника в потоке не должна оставлять индикатор выполнения навсегда
+            let res = panic::catch_unwind(AssertUnwindSafe(|| {
+                cipher.ctr_crypt_progress(&data, s, &iv, &|done, total| {
+                    let _ = sender.unbounded_send(CtrTask::Progress(done, total));
+                })
+            })).unwrap_or_else(|_| Err("Внутренняя ошибка при выполнении режима CTR".to_string()));
+
+            let _ = sender.unbounded_send(match (res, output) {
+                (Ok(res), Some(path)) => match fs::write(&path, res) {
+                    Ok(_) => CtrTask::Saved(path),
+                    Err(_) => CtrTask::Failed("Не удалось сохранить файл с зашифрованным текстом".to_string())
+                },
+                (Ok(res), None) => match String::from_utf8(res) {
+                    Ok(text) => CtrTask::Decrypted(text),
+                    Err(_) => CtrTask::Failed("Некорректные ключи для данного файла".to_string())
+                },
+                (Err(msg), _) => CtrTask::Failed(msg)
+            });
+        });
+
+        Task::run(receiver, Message::CtrTaskUpdate)
+    }
 
-            if name == "K" {
-                bytes = hex_to_bytes(value);
-                bytes.reverse();
-                
-                main_key = bytes;
-            } 
-            else if name == "S"
-            {
-                s = match value.parse::<u32>() {
-                    Ok(val) => val,
-                    Err(_) => return Err("Ошибка распознавания файла с ключами".to_string())
-                };
-            } 
-            else if name == "Z"
-            {
-                z = match value.parse::<u32>() {
-                    Ok(val) => val,
-                    Err(_) => return Err("Ошибка распознавания файла с ключами".to_string())
-                };
-            } 
-            else if name == "IV"
-            {
-                bytes = hex_to_bytes(value);
-                bytes.reverse();
+    // Случайные параметры режимов s, z и IV для выбранного шифра
+    fn generate_mods_param(&mut self)
+    {
+        let n = self.current_cipher.block_size() as u32;
 
-                iv = bytes;
-            }
-            else {
-                bytes = hex_to_bytes(value);
-                bytes.reverse();
+        let mut s= rand::random::<u32>() % (8 * n);
+        let z = rand::random::<u32>() % 13 + 1;
 
-                let mut arr = [0u8; 16];
-                arr.copy_from_slice(&bytes);
-                round_keys.push(arr);
-            }
+        if s < 8 {s += 11;}
+
+        self.mods_param = (s, z, algorithms::random_vec((z * n) as usize));
+    }
+
+    // Выбор файла с ключом экспорта и его загрузка (пароль - из поля пароля)
+    fn pick_kek(&self) -> Result<CipherKey, String>
+    {
+        match rfd::FileDialog::new()
+            .set_title(format!(" Выберите файл с ключом экспорта для алгоритма {}...", self.current_cipher))
+            .pick_file()
+            {
+                Some(path) => Ok(self.get_keys_from_file(&path)?.0),
+                None => Err("Некорректный файл с ключом экспорта".to_string())
         }
+    }
 
-        if iv.is_empty() {return Err("Некорректный файл с ключами".to_string());}
+    // Вычисление имитовставки data и сохранение ее в файл
+    fn mac_compute(&mut self, cipher: &CipherModes, data: &[u8])
+    {
+        let mac = match cipher.cmac(data) {
+            Ok(res) => res,
+            Err(msg) => {
+                self.compute_error = msg;
+                return;
+            }
+        };
 
-        Ok((main_key, round_keys, s, z, iv))
+        match rfd::FileDialog::new()
+            .set_title(" Сохранение файла с имитовставкой...")
+            .set_file_name("MAC")
+            .save_file()
+            {
+                Some(path) => {
+                    match fs::write(&path, &mac) {
+                        Ok(_) => self.compute_info = format!("Имитовставка {} записана в {}", to_hex(&mac), path.display()),
+                        Err(_) => self.compute_error = "Не удалось сохранить файл с имитовставкой".to_string(),
+                    };
+                },
+                None => self.compute_error = "Не удалось сохранить файл с данными".to_string()
+        };
+    }
+
+    // Проверка имитовставки tag для data
+    fn mac_verify(&mut self, cipher: &CipherModes, data: &[u8], tag: &[u8])
+    {
+        match cipher.cmac_verify(data, tag) {
+            Ok(true) => self.compute_info = "Имитовставка верна".to_string(),
+            Ok(false) => self.compute_error = "Имитовставка не совпадает: данные изменены или ключ другой".to_string(),
+            Err(msg) => self.compute_error = format!("Некорректная имитовставка: {}", msg)
+        };
     }
 
     pub fn update(&mut self, message: Message) -> iced::Task<Message>
     {
+        // Криптографические операции недоступны, если самотестирование не пройдено
+        if !self.self_test.passed() && !matches!(message, Message::Select | Message::CurrentState | Message::CopyClipboard(_))
+        {
+            self.state = Message::Select;
+            self.topbar_error = "Самотестирование не пройдено, криптографические операции заблокированы".to_string();
+            return Task::none();
+        }
+
         match message {
             Message::Select => {
                 self.state = Message::Select;
@@ -258,17 +612,42 @@ impl Cryptography {
 
                 if !text.is_empty() && text != "\n"
                 {
-                    match streebog_string(text, 256) 
+                    // При введенном ключе вычисляется HMAC вместо хэш-кода
+                    let res = match self.hmac_key.expose().is_empty() {
+                        true => streebog_string(text, 256),
+                        false => hmac_string(self.hmac_key.expose(), text, 256)
+                    };
+
+                    match res 
                     {
                         Ok(res) => self.streebog_hash = text_editor::Content::with_text(&res),
-                        Err(message) => self.compute_error = message
+                        Err(message) => self.topbar_error = message // На экране Стрибога выводятся только ошибки сверху
                     };
                 }
+            },
+            Message::HmacKeyInput(key) => {
+                // Предыдущее значение затирается при удалении
+                self.hmac_key = Secret::new(key);
             }
+            Message::BlockCipherChange(cipher) => {
+                self.info_error_msg_reset();
+
+                // Ключи и параметры режимов относятся к конкретному шифру
+                if self.current_cipher != cipher {
+                    self.current_cipher = cipher;
+                    self.set_keys(None);
+                    self.mods_param = (0, 0, Vec::new());
+                    self.keys_kuznechik_text = text_editor::Content::new();
+                }
+            },
             Message::KuznechickChangeMode(mode) => {
                 self.current_mode = Some(mode);
                 self.info_error_msg_reset();
             },
+            Message::PaddingChange(padding) => {
+                self.current_padding = padding;
+                self.info_error_msg_reset();
+            },
             Message::KuznechickSaveFile(text) => {
                 self.info_error_msg_reset();
                 
@@ -295,7 +674,7 @@ impl Cryptography {
                 self.info_error_msg_reset();
 
                 let path = match rfd::FileDialog::new()
-                    .set_title(" Выберите файл с ключами для алгоритма Кузнечик...")
+                    .set_title(format!(" Выберите файл с ключами для алгоритма {}...", self.current_cipher))
                     .pick_file()
                     {
                         Some(path_buf) => path_buf,
@@ -305,11 +684,12 @@ impl Cryptography {
                         }
                     };
 
-                match self.get_keys_from_file(&path)
+                let owner = match self.get_keys_from_file(&path)
                 {
-                    Ok(params) => { 
-                        self.keys_kuznechik = Kuznechik{keys: (params.0, params.1)};
-                        self.mods_param = (params.2, params.3, params.4)
+                    Ok((keys, meta)) => { 
+                        self.set_keys(Some(keys));
+                        self.mods_param = (meta.s, meta.z, meta.iv);
+                        meta.owner
                     },
                     Err(topbar_error) => {
                         self.topbar_error = topbar_error;
@@ -321,7 +701,7 @@ impl Cryptography {
                 match self.keys_to_string() {
                     Ok(res) => {
                         self.keys_kuznechik_text = text_editor::Content::with_text(&res);
-                        self.topbar_info = format!("Ключи загружены из {}", path.display());
+                        self.topbar_info = format!("Ключи загружены из {} (владелец {})", path.display(), owner);
                     },
                     Err(topbar_error) => self.topbar_error = topbar_error
                 };
@@ -333,29 +713,94 @@ impl Cryptography {
                     .set_title(" Сохранение файла с ключами...")
                     .save_file() {
                         Some(path) => {
-                            match self.keys_to_string() {
-                                Ok(res) => {
-                                    match fs::write(&path, res) {
-                                        Ok(_) => self.topbar_info = format!("Ключи записаны в {}", path.display()),
-                                        Err(_) => self.topbar_error = "Не удалось сохранить ключи в файл".to_string(),
-                                    };
-                                }
-                                Err(_) => self.topbar_error = "Не удалось сохранить файл с ключами".to_string()
+                            match self.save_keys_into_file(&path) {
+                                Ok(_) => self.topbar_info = match self.keys_password() {
+                                    Some(_) => format!("Ключи записаны в {} (защищены паролем)", path.display()),
+                                    None => format!("Ключи записаны в {}", path.display())
+                                },
+                                Err(topbar_error) => self.topbar_error = topbar_error
                             };
                         },
                         None => self.topbar_error = "Не удалось сохранить файл с ключами".to_string()
                 };
             },
-            Message::KuznechickKeysGenerate => {
+            Message::KuznechickKeysWrap => {
                 self.info_error_msg_reset();
-                self.keys_kuznechik = Kuznechik { keys: Kuznechik::key_generate() };
 
-                let mut s= rand::random::<u32>() % 128;
-                let z = rand::random::<u32>() % 13 + 1;
+                let keys = match &self.keys_kuznechik {
+                    Some(keys) => keys,
+                    None => {
+                        self.topbar_error = "Ключи не были созданы".to_string();
+                        return Task::none();
+                    }
+                };
 
-                if s < 8 {s += 11;}
+                let wrapped = match self.pick_kek().and_then(|kek| kek.wrap_key(keys)) {
+                    Ok(res) => res,
+                    Err(topbar_error) => {
+                        self.topbar_error = topbar_error;
+                        return Task::none();
+                    }
+                };
 
-                self.mods_param = (s, z, algorithms::random_vec((z * 16) as usize));
+                match rfd::FileDialog::new()
+                    .set_title(" Сохранение экспортированного ключа...")
+                    .set_file_name("KExp15")
+                    .save_file() {
+                        Some(path) => {
+                            match fs::write(&path, &wrapped) {
+                                Ok(_) => self.topbar_info = format!("Экспортированный ключ записан в {}", path.display()),
+                                Err(_) => self.topbar_error = "Не удалось сохранить экспортированный ключ".to_string()
+                            };
+                        },
+                        None => self.topbar_error = "Не удалось сохранить экспортированный ключ".to_string()
+                };
+            },
+            Message::KuznechickKeysUnwrap => {
+                self.info_error_msg_reset();
+
+                let kek = match self.pick_kek() {
+                    Ok(kek) => kek,
+                    Err(topbar_error) => {
+                        self.topbar_error = topbar_error;
+                        return Task::none();
+                    }
+                };
+
+                let keys = match read_file_titled(" Выберите файл с экспортированным ключом...").and_then(|data| kek.unwrap_key(&data)) {
+                    Ok(keys) => keys,
+                    Err(topbar_error) => {
+                        self.topbar_error = topbar_error;
+                        return Task::none();
+                    }
+                };
+
+                // Экспортированный ключ не содержит параметров режимов: текущие сохраняются, иначе вырабатываются новые
+                self.set_keys(Some(keys));
+                if self.mods_param.1 == 0 {
+                    self.generate_mods_param();
+                }
+
+                // Для отображения в GUI
+                match self.keys_to_string() {
+                    Ok(res) => {
+                        self.keys_kuznechik_text = text_editor::Content::with_text(&res);
+                        self.topbar_info = "Ключ импортирован".to_string();
+                    },
+                    Err(topbar_error) => self.topbar_error = topbar_error
+                };
+            },
+            Message::KeysPasswordInput(password) => {
+                // Предыдущее значение затирается при удалении
+                self.keys_password = Secret::new(password);
+            },
+            Message::KuznechickKeysGenerate => {
+                self.info_error_msg_reset();
+                self.set_keys(Some(match self.current_cipher {
+                    BlockCiphers::Kuznechik => CipherKey::Kuznechik(KuznechikKey::generate()),
+                    BlockCiphers::Magma => CipherKey::Magma(MagmaKey::generate())
+                }));
+                self.generate_mods_param();
 
                 // Для отображения в GUI
                 match self.keys_to_string() {
@@ -370,20 +815,34 @@ impl Cryptography {
             Message::KuznechickEncryptionCompute => {
                 self.info_error_msg_reset();
 
-                if self.keys_kuznechik.keys.0.is_empty() {
-                    self.compute_error = "Ключи не были созданы. Зайдите в раздел \"К ключам\" и загрузите или сгенерируйте ключи.".to_string();
+                if self.ctr_progress.is_some() {
+                    self.compute_error = "Дождитесь завершения шифрования".to_string();
                     return Task::none();
                 }
 
+                let cipher = match &self.cipher_modes {
+                    Some(modes) => Arc::clone(modes),
+                    None => {
+                        self.compute_error = "Ключи не были созданы. Зайдите в раздел \"К ключам\" и загрузите или сгенерируйте ключи.".to_string();
+                        return Task::none();
+                    }
+                };
+
                 match self.current_mode 
                 {
                     Some(KuznechickModes::CBC) => {
-                        let output: Vec<[u8; 16]> = block_cipher_modes::CipherModes::ecb_encrypt(
-                            &block_cipher_modes::CipherModes{keys: self.keys_kuznechik.clone()}, 
-                            self.kuzcnechik_text.text().as_bytes()
-                        );
-
-                        let output_bytes: Vec<u8> = output.iter().flatten().copied().collect();
+                        let output_bytes = match cipher.cbc_encrypt(
+                            self.kuzcnechik_text.text().as_bytes(),
+                            self.mods_param.1 as usize,
+                            &self.mods_param.2,
+                            self.current_padding
+                        ) {
+                            Ok(res) => res,
+                            Err(msg) => {
+                                self.compute_error = msg;
+                                return Task::none();
+                            }
+                        };
 
                         match rfd::FileDialog::new()
                             .set_title(" Сохранение файла с зашифрованными данными...")
@@ -400,13 +859,18 @@ impl Cryptography {
                         };
                     },
                     Some(KuznechickModes::CFB) => {
-                        let output  = block_cipher_modes::CipherModes::cfb_encrypt(
-                            &block_cipher_modes::CipherModes{keys: self.keys_kuznechik.clone()}, 
+                        let output = match cipher.cfb_encrypt(
                             self.kuzcnechik_text.text().as_bytes(),
                             self.mods_param.0 as usize,
                             self.mods_param.1 as usize,
                             &self.mods_param.2
-                        );
+                        ) {
+                            Ok(res) => res,
+                            Err(msg) => {
+                                self.compute_error = msg;
+                                return Task::none();
+                            }
+                        };
 
                         match rfd::FileDialog::new()
                             .set_title(" Сохранение файла с зашифрованными данными...")
@@ -423,37 +887,24 @@ impl Cryptography {
                         };
                     },
                     Some(KuznechickModes::CTR) => {
-                        let mut iv:[u8; 8] = [0; 8];
-                        iv.clone_from_slice(&self.mods_param.2[0..8]);
-
-                        let output = block_cipher_modes::CipherModes::ctr_crypt(
-                            &block_cipher_modes::CipherModes{keys: self.keys_kuznechik.clone()}, 
-                            self.kuzcnechik_text.text().as_bytes(),
-                            self.mods_param.0 as usize,
-                            &iv
-                        );
-
+                        // Файл выбирается заранее: шифрование идет в фоновом потоке
                         match rfd::FileDialog::new()
                             .set_title(" Сохранение файла с зашифрованными данными...")
                             .set_file_name("CTR")
                             .save_file()
                             {
-                                Some(path) => {
-                                    match fs::write(&path, output) {
-                                        Ok(_) => self.compute_info = format!("Результат записан в {}", path.display()),
-                                        Err(_) => self.compute_error = "Не удалось сохранить файл с зашифрованным текстом".to_string(),
-                                    };
-                                },
+                                Some(path) => return self.ctr_start(cipher, self.kuzcnechik_text.text().into_bytes(), Some(path)),
                                 None => self.compute_error = "Не удалось сохранить файл с данными".to_string()
                         };
                     },
                     Some(KuznechickModes::ECB) => {
-                        let output = block_cipher_modes::CipherModes::ecb_encrypt(
-                            &block_cipher_modes::CipherModes{keys: self.keys_kuznechik.clone()}, 
-                            self.kuzcnechik_text.text().as_bytes()
-                        );
-
-                        let bytes_output: Vec<u8> = output.iter().flatten().copied().collect();
+                        let bytes_output = match cipher.ecb_encrypt(self.kuzcnechik_text.text().as_bytes(), self.current_padding) {
+                            Ok(res) => res,
+                            Err(msg) => {
+                                self.compute_error = msg;
+                                return Task::none();
+                            }
+                        };
 
                         match rfd::FileDialog::new()
                             .set_title(" Сохранение файла с зашифрованными данными...")
@@ -470,13 +921,18 @@ impl Cryptography {
                         };
                     },
                     Some(KuznechickModes::OFB) => {
-                        let output = block_cipher_modes::CipherModes::ofb_crypt(
-                            &block_cipher_modes::CipherModes{keys: self.keys_kuznechik.clone()}, 
+                        let output = match cipher.ofb_crypt(
                             self.kuzcnechik_text.text().as_bytes(),
                             self.mods_param.0 as usize,
                             self.mods_param.1 as usize,
                             &self.mods_param.2
-                        );
+                        ) {
+                            Ok(res) => res,
+                            Err(msg) => {
+                                self.compute_error = msg;
+                                return Task::none();
+                            }
+                        };
 
                         match rfd::FileDialog::new()
                             .set_title(" Сохранение файла с зашифрованными данными...")
@@ -492,20 +948,100 @@ impl Cryptography {
                                 None => self.compute_error = "Не удалось сохранить файл с данными".to_string()
                         };
                     },
-                    //Some(KuznechickModes::MAC) => {},
+                    Some(KuznechickModes::CtrAcpkm) => {
+                        let output = match cipher.ctr_acpkm_crypt(self.kuzcnechik_text.text().as_bytes(), &self.mods_param.2) {
+                            Ok(res) => res,
+                            Err(msg) => {
+                                self.compute_error = msg;
+                                return Task::none();
+                            }
+                        };
+
+                        match rfd::FileDialog::new()
+                            .set_title(" Сохранение файла с зашифрованными данными...")
+                            .set_file_name("CTR-ACPKM")
+                            .save_file()
+                            {
+                                Some(path) => {
+                                    match fs::write(&path, output) {
+                                        Ok(_) => self.compute_info = format!("Результат записан в {}", path.display()),
+                                        Err(_) => self.compute_error = "Не удалось сохранить файл с зашифрованным текстом".to_string(),
+                                    };
+                                },
+                                None => self.compute_error = "Не удалось сохранить файл с данными".to_string()
+                        };
+                    },
+                    Some(KuznechickModes::OmacAcpkm) => {
+                        let mac = match cipher.omac_acpkm(self.kuzcnechik_text.text().as_bytes()) {
+                            Ok(res) => res,
+                            Err(msg) => {
+                                self.compute_error = msg;
+                                return Task::none();
+                            }
+                        };
+
+                        match rfd::FileDialog::new()
+                            .set_title(" Сохранение файла с имитовставкой...")
+                            .set_file_name("OMAC-ACPKM")
+                            .save_file()
+                            {
+                                Some(path) => {
+                                    match fs::write(&path, &mac) {
+                                        Ok(_) => self.compute_info = format!("Имитовставка {} записана в {}", to_hex(&mac), path.display()),
+                                        Err(_) => self.compute_error = "Не удалось сохранить файл с имитовставкой".to_string(),
+                                    };
+                                },
+                                None => self.compute_error = "Не удалось сохранить файл с данными".to_string()
+                        };
+                    },
+                    Some(KuznechickModes::Mac) => {
+                        let text = self.kuzcnechik_text.text();
+                        self.mac_compute(&cipher, text.as_bytes());
+                    },
+                    Some(KuznechickModes::CtrCmac) => {
+                        let output = match cipher.ctr_cmac_encrypt(self.kuzcnechik_text.text().as_bytes()) {
+                            Ok(res) => res,
+                            Err(msg) => {
+                                self.compute_error = msg;
+                                return Task::none();
+                            }
+                        };
+
+                        match rfd::FileDialog::new()
+                            .set_title(" Сохранение файла с зашифрованными данными...")
+                            .set_file_name("CTR-CMAC")
+                            .save_file()
+                            {
+                                Some(path) => {
+                                    match fs::write(&path, output) {
+                                        Ok(_) => self.compute_info = format!("Результат с имитовставкой записан в {}", path.display()),
+                                        Err(_) => self.compute_error = "Не удалось сохранить файл с зашифрованным текстом".to_string(),
+                                    };
+                                },
+                                None => self.compute_error = "Не удалось сохранить файл с данными".to_string()
+                        };
+                    },
                     None => {
-                        self.compute_error = "Ни один из режимов работы Кузнечика не был выбран".to_string();
+                        self.compute_error = format!("Ни один из режимов работы алгоритма {} не был выбран", self.current_cipher);
                     }
                 };
             }
             Message::KuznechickDecryptionCompute => {
                 self.info_error_msg_reset();
 
-                if self.keys_kuznechik.keys.0.is_empty() {
-                    self.compute_error = "Ключи не были созданы. Зайдите в раздел \"К ключам\" и загрузите или сгенерируйте ключи.".to_string();
+                if self.ctr_progress.is_some() {
+                    self.compute_error = "Дождитесь завершения шифрования".to_string();
                     return Task::none();
                 }
 
+                let cipher = match &self.cipher_modes {
+                    Some(modes) => Arc::clone(modes),
+                    None => {
+                        self.compute_error = "Ключи не были созданы. Зайдите в раздел \"К ключам\" и загрузите или сгенерируйте ключи.".to_string();
+                        return Task::none();
+                    }
+                };
+
                 let data = match read_file() {
                     Ok(data) => data,
                     Err(message) => {
@@ -517,9 +1053,11 @@ impl Cryptography {
                 match self.current_mode 
                 {
                     Some(KuznechickModes::CBC) => {
-                        let output = match block_cipher_modes::CipherModes::ecb_decrypt(
-                            &block_cipher_modes::CipherModes{keys: self.keys_kuznechik.clone()}, 
-                            &data
+                        let output = match cipher.cbc_decrypt(
+                            &data,
+                            self.mods_param.1 as usize,
+                            &self.mods_param.2,
+                            self.current_padding
                         ) {
                             Ok(res) => res,
                             Err(msg) => {
@@ -540,8 +1078,7 @@ impl Cryptography {
                         self.compute_info    = "Файл был расшифрован".to_string();
                     },
                     Some(KuznechickModes::CFB) => {
-                        let output = match block_cipher_modes::CipherModes::cfb_decrypt(
-                            &block_cipher_modes::CipherModes{keys: self.keys_kuznechik.clone()}, 
+                        let output = match cipher.cfb_decrypt(
                             &data,
                             self.mods_param.0 as usize,
                             self.mods_param.1 as usize,
@@ -565,16 +1102,15 @@ impl Cryptography {
                         self.kuzcnechik_text = text_editor::Content::with_text(decrypted_data);
                         self.compute_info    = "Файл был расшифрован".to_string();
                     },
-                    Some(KuznechickModes::CTR) => {
-                        let mut iv:[u8; 8] = [0; 8];
-                        iv.clone_from_slice(&self.mods_param.2[0..8]);
-
-                        let output = block_cipher_modes::CipherModes::ctr_crypt(
-                            &block_cipher_modes::CipherModes{keys: self.keys_kuznechik.clone()}, 
-                            &data,
-                            self.mods_param.0 as usize,
-                            &iv
-                        );
+                    Some(KuznechickModes::CTR) => return self.ctr_start(cipher, data, None),
+                    Some(KuznechickModes::ECB) => {
+                        let output = match cipher.ecb_decrypt(&data, self.current_padding) {
+                            Ok(res) => res,
+                            Err(msg) => {
+                                self.compute_error = msg;
+                                return Task::none();
+                            }
+                        };
 
                         let decrypted_data = match from_utf8(&output) {
                             Ok(data) => data,
@@ -587,10 +1123,12 @@ impl Cryptography {
                         self.kuzcnechik_text = text_editor::Content::with_text(decrypted_data);
                         self.compute_info    = "Файл был расшифрован".to_string();
                     },
-                    Some(KuznechickModes::ECB) => {
-                        let output = match block_cipher_modes::CipherModes::ecb_decrypt(
-                            &block_cipher_modes::CipherModes{keys: self.keys_kuznechik.clone()}, 
-                            &data
+                    Some(KuznechickModes::OFB) => {
+                        let output = match cipher.ofb_crypt(
+                            &data,
+                            self.mods_param.0 as usize,
+                            self.mods_param.1 as usize,
+                            &self.mods_param.2
                         ) {
                             Ok(res) => res,
                             Err(msg) => {
@@ -610,14 +1148,14 @@ impl Cryptography {
                         self.kuzcnechik_text = text_editor::Content::with_text(decrypted_data);
                         self.compute_info    = "Файл был расшифрован".to_string();
                     },
-                    Some(KuznechickModes::OFB) => {
-                        let output = block_cipher_modes::CipherModes::ofb_crypt(
-                            &block_cipher_modes::CipherModes{keys: self.keys_kuznechik.clone()}, 
-                            &data,
-                            self.mods_param.0 as usize,
-                            self.mods_param.1 as usize,
-                            &self.mods_param.2
-                        );
+                    Some(KuznechickModes::CtrAcpkm) => {
+                        let output = match cipher.ctr_acpkm_crypt(&data, &self.mods_param.2) {
+                            Ok(res) => res,
+                            Err(msg) => {
+                                self.compute_error = msg;
+                                return Task::none();
+                            }
+                        };
 
                         let decrypted_data = match from_utf8(&output) {
                             Ok(data) => data,
@@ -630,8 +1168,76 @@ impl Cryptography {
                         self.kuzcnechik_text = text_editor::Content::with_text(decrypted_data);
                         self.compute_info    = "Файл был расшифрован".to_string();
                     },
-                    //Some(KuznechickModes::MAC) => {},
-                    None => self.compute_error = "Ни один из режимов работы Кузнечика не был выбран.".to_string()
+                    Some(KuznechickModes::OmacAcpkm) => {
+                        self.compute_error = "OMAC-ACPKM вычисляет имитовставку, расшифрование для него не определено".to_string();
+                    },
+                    // Выбранный файл - имитовставка введенного текста
+                    Some(KuznechickModes::Mac) => {
+                        let text = self.kuzcnechik_text.text();
+                        self.mac_verify(&cipher, text.as_bytes(), &data);
+                    },
+                    // При несовпадении имитовставки расшифрованный текст не выводится
+                    Some(KuznechickModes::CtrCmac) => {
+                        let output = match cipher.ctr_cmac_decrypt(&data) {
+                            Ok(res) => res,
+                            Err(msg) => {
+                                self.compute_error = msg;
+                                return Task::none();
+                            }
+                        };
+
+                        let decrypted_data = match from_utf8(&output) {
+                            Ok(data) => data,
+                            Err(_) => {
+                                self.compute_error = "Расшифрованные данные не являются текстом".to_string();
+                                return Task::none();
+                            }
+                        };
+
+                        self.kuzcnechik_text = text_editor::Content::with_text(decrypted_data);
+                        self.compute_info    = "Файл был расшифрован, имитовставка верна".to_string();
+                    },
+                    None => self.compute_error = format!("Ни один из режимов работы алгоритма {} не был выбран.", self.current_cipher)
+                };
+            },
+            Message::MacFileCompute => {
+                self.info_error_msg_reset();
+
+                let cipher = match &self.cipher_modes {
+                    Some(modes) => Arc::clone(modes),
+                    None => {
+                        self.compute_error = "Ключи не были созданы. Зайдите в раздел \"К ключам\" и загрузите или сгенерируйте ключи.".to_string();
+                        return Task::none();
+                    }
+                };
+
+                match read_file_titled(" Выберите файл для вычисления имитовставки...") {
+                    Ok(data) => self.mac_compute(&cipher, &data),
+                    Err(message) => self.topbar_error = message
+                };
+            },
+            Message::MacFileVerify => {
+                self.info_error_msg_reset();
+
+                let cipher = match &self.cipher_modes {
+                    Some(modes) => Arc::clone(modes),
+                    None => {
+                        self.compute_error = "Ключи не были созданы. Зайдите в раздел \"К ключам\" и загрузите или сгенерируйте ключи.".to_string();
+                        return Task::none();
+                    }
+                };
+
+                let data = match read_file_titled(" Выберите проверяемый файл...") {
+                    Ok(data) => data,
+                    Err(message) => {
+                        self.topbar_error = message;
+                        return Task::none();
+                    }
+                };
+
+                match read_file_titled(" Выберите файл с имитовставкой...") {
+                    Ok(tag) => self.mac_verify(&cipher, &data, &tag),
+                    Err(message) => self.topbar_error = message
                 };
             },
             Message::InputTextEditor(content) => {
@@ -647,6 +1253,24 @@ impl Cryptography {
                 self.info_error_msg_reset();
                 return clipboard::write(content).map(|_: ()| Message::CurrentState);
             },
+            Message::CtrTaskUpdate(update) => {
+                match update {
+                    CtrTask::Progress(done, total) => self.ctr_progress = Some((done, total)),
+                    CtrTask::Saved(path) => {
+                        self.ctr_progress = None;
+                        self.compute_info = format!("Результат записан в {}", path.display());
+                    },
+                    CtrTask::Decrypted(text) => {
+                        self.ctr_progress = None;
+                        self.kuzcnechik_text = text_editor::Content::with_text(&text);
+                        self.compute_info = "Файл был расшифрован".to_string();
+                    },
+                    CtrTask::Failed(msg) => {
+                        self.ctr_progress = None;
+                        self.compute_error = msg;
+                    }
+                };
+            },
             Message::PickFile => {
                 self.info_error_msg_reset();
 
@@ -771,7 +1395,7 @@ impl Cryptography {
                             column![
                                 column![
                                     button(text(" RSA (Асимметричное шифрование)").size(24))
-                                        .on_press(Message::RSA)
+                                        .on_press_maybe(self.self_test.passed().then_some(Message::RSA))
                                         .style(|_theme, status| button_style_rsa(status))
                                 ].width(Length::Fill).align_x(iced::Alignment::Center),
                                 text("Криптографический алгоритм с открытым и закрытым ключом, основывающийся на вычислительной сложности задачи факторизации больших полупростых чисел.")
@@ -782,7 +1406,7 @@ impl Cryptography {
                             column![
                                 column![
                                     button(text(" Streebog (Хэширование)").size(24))
-                                        .on_press(Message::Streebog)
+                                        .on_press_maybe(self.self_test.passed().then_some(Message::Streebog))
                                         .style(|_theme, status| button_style_streebog(status))
                                 ].width(Length::Fill).align_x(iced::Alignment::Center),
                                 text("Криптографический алгоритм вычисления хеш-функции с размером блока входных данных 512 бит и размером хеш-кода 256 или 512 бит.")
@@ -792,18 +1416,30 @@ impl Cryptography {
                             ].height(Length::Fill),
                             column![
                                 column![
-                                    button(text(" Kuznehcik (Блочное шифрование)").size(24))
-                                        .on_press(Message::KuznechickKeys)
+                                    button(text(" Kuznechik, Magma (Блочное шифрование)").size(24))
+                                        .on_press_maybe(self.self_test.passed().then_some(Message::KuznechickKeys))
                                         .style(|_theme, status| button_style_kuznechik(status))
                                 ].width(Length::Fill).align_x(iced::Alignment::Center),
-                                text("Симметричный алгоритм блочного шифрования с размером блока 128 бит и длиной ключа 256 бит")
+                                text("Симметричные алгоритмы блочного шифрования с размером блока 128 бит (Кузнечик) или 64 бита (Магма) и длиной ключа 256 бит")
                                     .size(24)
                                     .width(500)
                                     .wrapping(text::Wrapping::Glyph)
                             ].height(Length::Fill),
                             text("")
                                 .width(50)
-                        ].height(Length::Fill).spacing(100)
+                        ].height(Length::Fill).spacing(100),
+                        // Отчет самотестирования по контрольным примерам ГОСТ
+                        column![
+                            text(if self.self_test.passed() {
+                                    "Самотестирование пройдено"
+                                } else {
+                                    "Самотестирование не пройдено, криптографические операции заблокированы"
+                                })
+                                .size(24)
+                                .color(if self.self_test.passed() { iced::Color::from_rgb(0.0, 0.6, 0.0) } else { iced::Color::from_rgb(0.8, 0.0, 0.0) }),
+                            text(self.self_test.to_string())
+                                .size(16)
+                        ].width(Length::Fill).align_x(iced::Alignment::Center)
                     ].spacing(40));
 
                 column = column.spacing(5);
@@ -818,8 +1454,14 @@ impl Cryptography {
                         text("")
                             .size(48)
                             .width(Length::Fill)
-                            .align_x(iced::alignment::Horizontal::Center)
-                    ]
+                            .align_x(iced::alignment::Horizontal::Center),
+                        text_input("Ключ HMAC в hex (необязательно, Р 50.1.113-2016)", self.hmac_key.expose())
+                            .secure(true)
+                            .on_input(Message::HmacKeyInput)
+                            .style(|_theme, _status| text_input_style())
+                            .size(18.0)
+                            .width(Length::Fixed(530.0))
+                    ].spacing(5)
                 );
 
                 if !self.topbar_error.is_empty()
@@ -879,7 +1521,7 @@ impl Cryptography {
                             ],
                             center(column![
                                 button(column![
-                                        text("Хэшировать")
+                                        text(if self.hmac_key.expose().is_empty() { "Хэшировать" } else { "HMAC" })
                                             .size(24), 
                                         text("\u{E830}")
                                             .font(CUSTOM_FONT)
@@ -923,15 +1565,31 @@ impl Cryptography {
             Message::KuznechickKeys => {
                 column = column.push(
                     column![
-                        text("Управление криптографическими ключами алгоритма Кузнечик (ГОСТ Р 34.12-2018)")
+                        text(format!("Управление криптографическими ключами алгоритма {} (ГОСТ Р 34.12-2018)", self.current_cipher))
                             .size(30)
                             .width(Length::Fill)
                             .align_x(iced::alignment::Horizontal::Center),
                         text("")
                             .size(48)
                             .width(Length::Fill)
-                            .align_x(iced::alignment::Horizontal::Center)
-                    ]
+                            .align_x(iced::alignment::Horizontal::Center),
+                        combo_box(
+                            &self.block_ciphers,
+                            "Выберите блочный шифр...",
+                            Some(&self.current_cipher),
+                            Message::BlockCipherChange
+                        )
+                            .input_style(|_style, _| combo_box_input_style())
+                            .menu_style(|_style| combo_box_menu_style())
+                            .size(18.0)
+                            .width(Length::Fixed(530.0)),
+                        text_input("Пароль для файла с ключами (необязательно)", self.keys_password.expose())
+                            .secure(true)
+                            .on_input(Message::KeysPasswordInput)
+                            .style(|_theme, _status| text_input_style())
+                            .size(18.0)
+                            .width(Length::Fixed(530.0))
+                    ].spacing(5)
                 );
 
                 if !self.topbar_error.is_empty()
@@ -1007,6 +1665,22 @@ impl Cryptography {
                                             .size(24), 
                                         tooltip::Position::Top
                                     ),
+                                    tooltip(
+                                        button(text("Экспорт").size(24))
+                                            .style(|_theme, status| button_style_kuznechik(status))
+                                            .on_press(Message::KuznechickKeysWrap),
+                                        text("Экспорт ключа на ключе из файла (KExp15)")
+                                            .size(24),
+                                        tooltip::Position::Top
+                                    ),
+                                    tooltip(
+                                        button(text("Импорт").size(24))
+                                            .style(|_theme, status| button_style_kuznechik(status))
+                                            .on_press(Message::KuznechickKeysUnwrap),
+                                        text("Импорт ключа на ключе из файла (KImp15)")
+                                            .size(24),
+                                        tooltip::Position::Top
+                                    ),
                                     button(row![
                                         text("Сгенерировать ключи ")
                                             .size(24),
@@ -1019,7 +1693,7 @@ impl Cryptography {
                                 ].spacing(10),
                                 text_editor(&self.keys_kuznechik_text)
                                     .size(24)
-                                    .placeholder("Здесь будут отображаться криптографические ключи для Кузнечика")
+                                    .placeholder("Здесь будут отображаться криптографические ключи выбранного шифра")
                                     .style(|_theme, _style| text_editor_style_read())
                                     .wrapping(text::Wrapping::WordOrGlyph)
                                     .height(1000)
@@ -1027,9 +1701,12 @@ impl Cryptography {
                             ]]);
             },
             Message::KuznechickEncryption => {
+                // В режиме MAC кнопки вычисляют и проверяют имитовставку вместо шифрования
+                let mac_mode = matches!(self.current_mode, Some(KuznechickModes::Mac));
+
                 column = column.push(
                     column![
-                        text("Шифрование алгоритмом Кузнечик (ГОСТ Р 34.12-2018)")
+                        text(format!("Шифрование алгоритмом {} (ГОСТ Р 34.12-2018)", self.current_cipher))
                             .size(32)
                             .width(Length::Fill)
                             .align_x(iced::alignment::Horizontal::Center),
@@ -1116,7 +1793,7 @@ impl Cryptography {
                                 ].spacing(10),
                                 text_editor(&self.kuzcnechik_text)
                                     .size(24)
-                                    .placeholder("Здесь будет отображаться текст, загруженный из файла или написанный вами, для шифрования алгоритмом Кузнечик")
+                                    .placeholder("Здесь будет отображаться текст, загруженный из файла или написанный вами, для шифрования выбранным алгоритмом")
                                     .style(|_theme, _style| text_editor_style_write())
                                     .wrapping(text::Wrapping::WordOrGlyph)
                                     .on_action(Message::InputTextEditor)
@@ -1175,27 +1852,60 @@ impl Cryptography {
                                             }),
                                         text(&self.compute_info)
                                             .size(24),
-                                        combo_box(
-                                            &self.kuznechik_modes, 
-                                            "Выберите режим работы...", 
-                                            self.current_mode.as_ref(), 
-                                            Message::KuznechickChangeMode
-                                        )
-                                            .input_style(|_style, _| combo_box_input_style())
-                                            .menu_style(|_style| combo_box_menu_style())
-                                            .size(18.0)
-                                            .width(Length::Fixed(530.0)),
-                                        button(text("Шифровать данные").align_x(iced::alignment::Horizontal::Center).size(24))
+                                        row![
+                                            combo_box(
+                                                &self.kuznechik_modes, 
+                                                "Выберите режим работы...", 
+                                                self.current_mode.as_ref(), 
+                                                Message::KuznechickChangeMode
+                                            )
+                                                .input_style(|_style, _| combo_box_input_style())
+                                                .menu_style(|_style| combo_box_menu_style())
+                                                .size(18.0)
+                                                .width(Length::Fixed(530.0)),
+                                            // Дополнение используется только в ECB и CBC
+                                            combo_box(
+                                                &self.paddings, 
+                                                "Дополнение...", 
+                                                Some(&self.current_padding), 
+                                                Message::PaddingChange
+                                            )
+                                                .input_style(|_style, _| combo_box_input_style())
+                                                .menu_style(|_style| combo_box_menu_style())
+                                                .size(18.0)
+                                                .width(Length::Fixed(250.0))
+                                        ].spacing(10),
+                                        button(text(if mac_mode { "Имитовставка текста" } else { "Шифровать данные" }).align_x(iced::alignment::Horizontal::Center).size(24))
                                             .on_press(Message::KuznechickEncryptionCompute)
                                             .style(|_theme, status| button_style_kuznechik(status))
                                             .padding(15)
                                             .width(Length::Fixed(530.0)),
-                                        button(text("Расшифровать данные").align_x(iced::alignment::Horizontal::Center).size(24))
+                                        button(text(if mac_mode { "Проверить текст" } else { "Расшифровать данные" }).align_x(iced::alignment::Horizontal::Center).size(24))
                                             .on_press(Message::KuznechickDecryptionCompute)
                                             .style(|_theme, status| button_style_kuznechik(status))
                                             .padding(15)
                                             .width(Length::Fixed(530.0))
                                     ].spacing(15)
+                                     // Имитовставка для произвольного файла, а не текста из редактора
+                                     .push_maybe(mac_mode.then(|| row![
+                                        button(text("Имитовставка файла").align_x(iced::alignment::Horizontal::Center).size(24))
+                                            .on_press(Message::MacFileCompute)
+                                            .style(|_theme, status| button_style_kuznechik(status))
+                                            .padding(15)
+                                            .width(Length::Fixed(260.0)),
+                                        button(text("Проверить файл").align_x(iced::alignment::Horizontal::Center).size(24))
+                                            .on_press(Message::MacFileVerify)
+                                            .style(|_theme, status| button_style_kuznechik(status))
+                                            .padding(15)
+                                            .width(Length::Fixed(260.0))
+                                     ].spacing(10)))
+                                     // Ход шифрования в режиме CTR
+                                     .push_maybe(self.ctr_progress.map(|(done, total)| column![
+                                        progress_bar(0.0..=1.0, if total == 0 { 1.0 } else { done as f32 / total as f32 })
+                                            .width(Length::Fixed(530.0)),
+                                        text(format!("Обработано {} из {} байт", done, total))
+                                            .size(24)
+                                     ].spacing(5)))
                                      .align_x(iced::Alignment::Center)
                                 ]
                             ),
@@ -1234,9 +1944,15 @@ impl Cryptography {
 
 // Чтение из файла байтов
 fn read_file() -> Result<Vec<u8>, String>
+{
+    read_file_titled(" Выберите файл...")
+}
+
+// Чтение из файла байтов с заголовком title у окна выбора файла
+fn read_file_titled(title: &str) -> Result<Vec<u8>, String>
 {
     let path = match rfd::FileDialog::new()
-        .set_title(" Выберите файл...")
+        .set_title(title)
         .pick_file()
         {
             Some(path_buf) => path_buf,
@@ -1250,4 +1966,123 @@ fn read_file() -> Result<Vec<u8>, String>
         Ok(data) => Ok(data),
         Err(_) => Err("Файла не существует".to_string())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gui_cbc_is_not_ecb() {
+        // Одинаковые блоки открытого текста дают в ECB одинаковые блоки шифротекста
+        // ("Кузнечик" - 16 байт в UTF-8, целое число блоков обоих шифров)
+        let text = "Кузнечик".repeat(4);
+
+        for (key, n) in [
+            (CipherKey::Kuznechik(KuznechikKey::generate()), 16),
+            (CipherKey::Magma(MagmaKey::generate()), 8)
+        ] {
+            let cipher = key.modes();
+            let z = 3;
+            let iv = algorithms::random_vec(n * z);
+
+            let ecb = cipher.ecb_encrypt(text.as_bytes(), Padding::Procedure2).unwrap();
+            let cbc = cipher.cbc_encrypt(text.as_bytes(), z, &iv, Padding::Procedure2).unwrap();
+
+            assert_eq!(ecb[..n], ecb[16..16 + n]);
+            assert_eq!(cbc.len(), ecb.len());
+            assert_ne!(cbc, ecb);
+            assert_ne!(cbc[..n], cbc[16..16 + n]);
+
+            let decrypted = cipher.cbc_decrypt(&cbc, z, &iv, Padding::Procedure2).unwrap();
+            assert_eq!(from_utf8(&decrypted).unwrap(), text);
+
+            // Шифротекст CBC не расшифровывается как ECB
+            assert_ne!(cipher.ecb_decrypt(&cbc, Padding::None).unwrap(), text.as_bytes());
+        }
+    }
+
+    #[test]
+    fn test_gui_mac_verify() {
+        let text = "Кузнечик".repeat(3);
+
+        for (key, n) in [
+            (CipherKey::Kuznechik(KuznechikKey::generate()), 16),
+            (CipherKey::Magma(MagmaKey::generate()), 8)
+        ] {
+            let cipher = key.modes();
+            let mac = cipher.cmac(text.as_bytes()).unwrap();
+
+            assert_eq!(mac.len(), n);
+            assert!(cipher.cmac_verify(text.as_bytes(), &mac).unwrap());
+            assert!(!cipher.cmac_verify(&text.as_bytes()[1..], &mac).unwrap());
+
+            // Имитовставка, вычисленная ранее, проверяется на том же загруженном ключе
+            let reloaded = match &key {
+                CipherKey::Kuznechik(key) => CipherKey::Kuznechik(key.clone_secret()),
+                CipherKey::Magma(key) => CipherKey::Magma(key.clone_secret())
+            };
+            assert!(reloaded.modes().cmac_verify(text.as_bytes(), &mac).unwrap());
+            assert!(cipher.cmac_verify(text.as_bytes(), &[]).is_err());
+        }
+    }
+
+    #[test]
+    fn test_gui_ctr_cmac_rejects_tampering() {
+        for key in [CipherKey::Kuznechik(KuznechikKey::generate()), CipherKey::Magma(MagmaKey::generate())] {
+            let cipher = key.modes();
+            let text = "Кузнечик".repeat(3);
+
+            let mut data = cipher.ctr_cmac_encrypt(text.as_bytes()).unwrap();
+            assert_eq!(from_utf8(&cipher.ctr_cmac_decrypt(&data).unwrap()).unwrap(), text);
+
+            data[10] ^= 1;
+            assert!(cipher.ctr_cmac_decrypt(&data).is_err());
+        }
+    }
+
+    #[test]
+    fn test_gui_key_wrap() {
+        for (kek, key) in [
+            (CipherKey::Kuznechik(KuznechikKey::generate()), CipherKey::Kuznechik(KuznechikKey::generate())),
+            (CipherKey::Magma(MagmaKey::generate()), CipherKey::Magma(MagmaKey::generate()))
+        ] {
+            let mut wrapped = kek.wrap_key(&key).unwrap();
+            let master = |key: &CipherKey| match key {
+                CipherKey::Kuznechik(key) => *key.master_key(),
+                CipherKey::Magma(key) => *key.master_key()
+            };
+            assert_eq!(master(&kek.unwrap_key(&wrapped).unwrap()), master(&key));
+
+            wrapped[0] ^= 1;
+            assert!(kek.unwrap_key(&wrapped).is_err());
+            assert!(kek.unwrap_key(&[]).is_err());
+        }
+
+        let kek = CipherKey::Magma(MagmaKey::generate());
+        assert!(kek.wrap_key(&CipherKey::Kuznechik(KuznechikKey::generate())).is_err());
+    }
+
+    #[test]
+    fn test_gui_ctr_progress_matches_ctr() {
+        let text = "Кузнечик".repeat(1000);
+
+        for key in [CipherKey::Kuznechik(KuznechikKey::generate()), CipherKey::Magma(MagmaKey::generate())] {
+            let cipher = key.modes();
+            let iv = algorithms::random_vec(32);
+            let last = std::sync::Mutex::new((0, 0));
+
+            let res = cipher.ctr_crypt_progress(text.as_bytes(), 9, &iv, &|done, total| {
+                let mut last = last.lock().unwrap();
+                *last = (*last).max((done, total));
+            }).unwrap();
+
+            let expected = match &cipher {
+                CipherModes::Kuznechik(modes, _) => modes.ctr_crypt(text.as_bytes(), 9, &iv[..8]).unwrap(),
+                CipherModes::Magma(modes, _) => modes.ctr_crypt(text.as_bytes(), 9, &iv[..4]).unwrap()
+            };
+            assert_eq!(res, expected);
+            assert_eq!(last.into_inner().unwrap(), (text.len(), text.len()));
+        }
+    }
+}
@@ -11,7 +11,8 @@ use crate::gui::cryptography::Cryptography;
 
 pub struct App 
 {
-    screen: GUI
+    screen: GUI,
+    self_test: algorithms::self_test::SelfTestReport
 }
 
 #[derive(Debug, Clone)]
@@ -24,8 +25,10 @@ impl App
 {
     fn new() -> Self
     {
+        // Проверка алгоритмов на контрольных примерах ГОСТ до начала работы
         Self { 
-            screen: GUI::Autorhization(Credentials::new())
+            screen: GUI::Autorhization(Credentials::new()),
+            self_test: algorithms::self_test()
         }
     }
 
@@ -37,7 +40,7 @@ impl App
                 {
                     if credentials.access == true
                     {
-                        self.screen = GUI::Cryptography(Cryptography::new(credentials.login.clone()));
+                        self.screen = GUI::Cryptography(Cryptography::new(credentials.login.clone(), self.self_test.clone()));
                         return Task::done(Message::Cryptography(cryptography::Message::Select));
                     }
 